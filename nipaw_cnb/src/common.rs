@@ -1,115 +1,88 @@
-use chrono::{NaiveDate, Utc, Weekday};
+use crate::model::{
+	Asset, BranchDetail, Calendar, Comment, Commit, CommitSignature, CommitStats, ErrorBody, Issue,
+	Org, Release, Repo, Tag, User,
+};
+use chrono::{NaiveDate, NaiveTime, Weekday};
 use itertools::Itertools;
+use nipaw_core::error::ApiErrorBody;
+use nipaw_core::types::branch::BranchInfo;
+use nipaw_core::types::comment::CommentInfo;
+use nipaw_core::types::issue::{IssueInfo, IssueState};
+use nipaw_core::types::release::{ReleaseAsset, ReleaseInfo};
 use nipaw_core::types::repo::Visibility;
+use nipaw_core::types::tag::{TagInfo, TaggerInfo};
 use nipaw_core::types::{
 	commit::{CommitData, CommitInfo, StatsInfo, UserInfo as CommitUserInfo},
 	org::OrgInfo,
 	repo::RepoInfo,
 	user::{ContributionData, ContributionResult, UserInfo},
 };
-use serde::{Deserialize, Serialize};
-use serde_json::Value;
-
-#[derive(Serialize, Deserialize)]
-pub struct JsonValue(pub(crate) Value);
-impl From<JsonValue> for UserInfo {
-	fn from(json_value: JsonValue) -> Self {
-		let user_info = json_value.0;
-		let login = user_info.get("username").and_then(|v| v.as_str()).unwrap();
+use nipaw_core::{
+	Error, Result,
+	decode::{DecodeError, entity_name},
+};
+
+impl From<User> for UserInfo {
+	fn from(user: User) -> Self {
 		UserInfo {
-			id: user_info.get("id").and_then(|v| v.as_str()).unwrap().to_string(),
-			login: login.to_string(),
-			name: user_info.get("nickname").and_then(|v| v.as_str()).map(|s| s.to_string()),
-			avatar_url: user_info.get("avatar_url").and_then(|v| v.as_str()).unwrap().to_string(),
-			email: user_info.get("email").and_then(|v| v.as_str()).map(|s| s.to_string()),
-			followers: user_info.get("follower_count").and_then(|v| v.as_u64()).unwrap(),
-			following: user_info.get("follow_count").and_then(|v| v.as_u64()).unwrap(),
-			public_repo_count: user_info.get("repo_count").and_then(|v| v.as_u64()).unwrap_or(0),
+			id: user.id,
+			login: user.username,
+			name: user.nickname,
+			avatar_url: String::new(),
+			email: user.email,
+			followers: user.follower_count,
+			following: user.follow_count,
+			public_repo_count: user.repo_count.unwrap_or(0),
+			raw: None,
 		}
 	}
 }
 
-impl From<JsonValue> for RepoInfo {
-	fn from(json_value: JsonValue) -> Self {
-		let repo_info = json_value.0;
-		let is_public = repo_info
-			.get("visibility_level")
-			.and_then(|v| v.as_str())
-			.map(|s| s.to_lowercase() == "public")
-			.unwrap_or(false);
-
+impl From<Repo> for RepoInfo {
+	fn from(repo: Repo) -> Self {
+		let is_public = repo.visibility_level.is_some_and(|s| s.to_lowercase() == "public");
 		RepoInfo {
-			id: repo_info.get("id").and_then(|v| v.as_str()).unwrap().to_string(),
-			owner: repo_info
-				.get("owner")
-				.and_then(|v| v.get("login"))
-				.and_then(|v| v.as_str())
-				.unwrap()
-				.to_string(),
-			name: repo_info.get("name").and_then(|v| v.as_str()).unwrap().to_string(),
-			full_name: repo_info.get("full_name").and_then(|v| v.as_str()).unwrap().to_string(),
-			description: repo_info
-				.get("description")
-				.and_then(|v| v.as_str())
-				.map(|s| s.to_string()),
+			id: repo.id,
+			owner: repo.owner.login,
+			name: repo.name,
+			full_name: repo.full_name,
+			description: repo.description,
 			visibility: if is_public { Visibility::Public } else { Visibility::Private },
-			fork: repo_info
-				.get("forked_from_repo")
-				.and_then(|v| v.get("path"))
-				.and_then(|v| v.as_str())
-				.is_some(),
-			fork_count: repo_info.get("fork_count").and_then(|v| v.as_u64()).unwrap_or(0),
-			language: repo_info.get("language").and_then(|v| v.as_str()).map(|s| s.to_string()),
-			star_count: repo_info.get("star_count").and_then(|v| v.as_u64()).unwrap_or(0),
-			default_branch: repo_info
-				.get("default_branch")
-				.and_then(|v| v.as_str())
-				.unwrap()
-				.to_string(),
-			created_at: repo_info
-				.get("created_at")
-				.and_then(|v| v.as_str())
-				.unwrap()
-				.to_string()
-				.parse()
-				.unwrap(),
-			updated_at: repo_info
-				.get("updated_at")
-				.and_then(|v| v.as_str())
-				.unwrap()
-				.to_string()
-				.parse()
-				.unwrap(),
-			pushed_at: repo_info
-				.get("updated_at")
-				.and_then(|v| v.as_str())
-				.unwrap()
-				.to_string()
-				.parse()
-				.unwrap(),
+			fork: repo.forked_from_repo.and_then(|source| source.path).is_some(),
+			fork_count: repo.fork_count.unwrap_or(0),
+			language: repo.language,
+			star_count: repo.star_count.unwrap_or(0),
+			default_branch: repo.default_branch.unwrap_or_default(),
+			created_at: repo.created_at,
+			updated_at: repo.updated_at,
+			pushed_at: repo.updated_at,
+			raw: None,
 		}
 	}
 }
 
-impl From<JsonValue> for ContributionResult {
-	fn from(value: JsonValue) -> Self {
-		let contribution_result = value.0;
+impl TryFrom<Calendar> for ContributionResult {
+	type Error = Error;
 
-		let contributions: Vec<Vec<ContributionData>> = contribution_result
-			.as_object()
-			.unwrap()
-			.iter()
+	fn try_from(value: Calendar) -> Result<Self> {
+		let contributions: Vec<Vec<ContributionData>> = value
+			.0
+			.into_iter()
 			.map(|(date_str, data)| {
-				let date = NaiveDate::parse_from_str(date_str, "%Y%m%d")
-					.unwrap()
-					.and_hms_opt(0, 0, 0)
-					.unwrap()
-					.and_local_timezone(Utc)
-					.unwrap();
-				let count = data.get("score").and_then(|v| v.as_u64()).unwrap_or(0) as u32;
-
-				ContributionData { date, count }
+				let date = NaiveDate::parse_from_str(&date_str, "%Y%m%d")
+					.map_err(|e| Error::Decode {
+						entity: entity_name::<ContributionResult>(),
+						field: date_str.clone(),
+						source: DecodeError::InvalidValue(e.to_string()),
+					})?
+					.and_time(NaiveTime::MIN)
+					.and_utc();
+				let count = data.score.unwrap_or(0) as u32;
+
+				Ok(ContributionData { date, count })
 			})
+			.collect::<Result<Vec<_>>>()?
+			.into_iter()
 			.sorted_by_key(|c| c.date)
 			.chunk_by(|c| {
 				let naive_date = c.date.naive_utc().date();
@@ -121,79 +94,161 @@ impl From<JsonValue> for ContributionResult {
 
 		let total = contributions.iter().flatten().map(|c| c.count).sum();
 
-		ContributionResult { total, contributions }
+		Ok(ContributionResult { total, contributions })
 	}
 }
 
-impl From<JsonValue> for CommitInfo {
-	fn from(value: JsonValue) -> Self {
-		let commit_info = value.0;
-		let commit_value = commit_info.get("commit").unwrap().clone();
-		let stats_value = commit_info.get("stats").unwrap().clone();
+impl From<Commit> for CommitInfo {
+	fn from(commit: Commit) -> Self {
 		CommitInfo {
-			sha: commit_info.get("sha").and_then(|v| v.as_str()).unwrap().to_string(),
-			commit: JsonValue(commit_value).into(),
-			stats: JsonValue(stats_value).into(),
+			sha: commit.sha,
+			commit: CommitData {
+				author: commit_user_info(commit.commit.author),
+				committer: commit_user_info(commit.commit.committer),
+				message: commit.commit.message,
+			},
+			stats: commit.stats.map(Into::into).unwrap_or_default(),
+			raw: None,
 		}
 	}
 }
 
-impl From<JsonValue> for CommitData {
-	fn from(value: JsonValue) -> Self {
-		let commit_data = value.0;
-		let author_value = commit_data.get("author").unwrap().clone();
-		let committer_value = commit_data.get("committer").unwrap().clone();
-		CommitData {
-			author: JsonValue(author_value).into(),
-			committer: JsonValue(committer_value).into(),
-			message: commit_data.get("message").and_then(|v| v.as_str()).unwrap().to_string(),
+fn commit_user_info(signature: CommitSignature) -> CommitUserInfo {
+	CommitUserInfo {
+		name: signature.name,
+		email: signature.email,
+		avatar_url: String::new(),
+		date: signature.date,
+	}
+}
+
+impl From<CommitStats> for StatsInfo {
+	fn from(stats: CommitStats) -> Self {
+		StatsInfo {
+			total: stats.total.unwrap_or(0),
+			additions: stats.additions.unwrap_or(0),
+			deletions: stats.deletions.unwrap_or(0),
 		}
 	}
 }
 
-impl From<JsonValue> for CommitUserInfo {
-	fn from(value: JsonValue) -> Self {
-		let user_info = value.0;
-		CommitUserInfo {
-			name: user_info.get("name").and_then(|v| v.as_str()).unwrap().to_string(),
-			email: user_info.get("email").and_then(|v| v.as_str()).map(|s| s.to_string()),
-			avatar_url: user_info.get("avatar_url").and_then(|v| v.as_str()).unwrap().to_string(),
-			date: user_info
-				.get("date")
-				.and_then(|v| v.as_str())
-				.unwrap()
-				.to_string()
-				.parse()
-				.unwrap(),
+impl From<Org> for OrgInfo {
+	fn from(org: Org) -> Self {
+		OrgInfo {
+			id: org.id,
+			login: org.login,
+			name: org.name,
+			email: org.email,
+			avatar_url: String::new(),
+			description: org.description,
+			follow_count: org.followers.unwrap_or(0),
+			raw: None,
 		}
 	}
 }
 
-impl From<JsonValue> for StatsInfo {
-	fn from(value: JsonValue) -> Self {
-		let stats_info = value.0;
-		StatsInfo {
-			total: stats_info.get("total").and_then(|v| v.as_u64()).unwrap_or(0),
-			additions: stats_info.get("additions").and_then(|v| v.as_u64()).unwrap_or(0),
-			deletions: stats_info.get("deletions").and_then(|v| v.as_u64()).unwrap_or(0),
+impl From<ErrorBody> for ApiErrorBody {
+	fn from(body: ErrorBody) -> Self {
+		ApiErrorBody {
+			code: body.errcode.map(|code| code.to_string()),
+			message: body.errmsg,
+			documentation_url: None,
 		}
 	}
 }
 
-impl From<JsonValue> for OrgInfo {
-	fn from(json_value: JsonValue) -> Self {
-		let org_info = json_value.0;
-		OrgInfo {
-			id: org_info.get("id").and_then(|v| v.as_u64()).unwrap(),
-			login: org_info.get("login").and_then(|v| v.as_str()).unwrap().to_string(),
-			name: org_info.get("name").and_then(|v| v.as_str()).map(|s| s.to_string()),
-			email: org_info.get("email").and_then(|v| v.as_str()).map(|s| s.to_string()),
-			avatar_url: org_info.get("avatar_url").and_then(|v| v.as_str()).unwrap().to_string(),
-			description: org_info
-				.get("description")
-				.and_then(|v| v.as_str())
-				.map(|s| s.to_string()),
-			follow_count: org_info.get("followers").and_then(|v| v.as_u64()).unwrap_or(0),
+impl From<BranchDetail> for BranchInfo {
+	fn from(branch: BranchDetail) -> Self {
+		BranchInfo {
+			name: branch.name,
+			sha: branch.commit.sha,
+			protected: branch.is_protected.unwrap_or(false),
+			raw: None,
+		}
+	}
+}
+
+impl From<Tag> for TagInfo {
+	fn from(tag: Tag) -> Self {
+		let annotated = tag.target_type.as_deref() == Some("tag") || tag.tagger.is_some();
+		TagInfo {
+			name: tag.name,
+			sha: tag.commit.map(|commit| commit.sha).or(tag.target).unwrap_or_default(),
+			tagger: tag.tagger.map(|tagger| TaggerInfo {
+				name: tagger.name,
+				email: tagger.email,
+				date: tagger.date,
+			}),
+			message: tag.message.filter(|_| annotated),
+			annotated,
+			raw: None,
+		}
+	}
+}
+
+impl From<Release> for ReleaseInfo {
+	fn from(release: Release) -> Self {
+		ReleaseInfo {
+			id: release.id,
+			tag_name: release.tag_name,
+			target_commitish: release.target_commitish,
+			name: release.name,
+			body: release.body,
+			draft: release.draft,
+			prerelease: release.prerelease,
+			author: release.author.map(|author| author.username),
+			created_at: release.created_at,
+			published_at: release.published_at,
+			assets: release.assets.into_iter().map(Into::into).collect(),
+			raw: None,
+		}
+	}
+}
+
+impl From<Asset> for ReleaseAsset {
+	fn from(asset: Asset) -> Self {
+		ReleaseAsset {
+			id: asset.id,
+			name: asset.name,
+			size: asset.size,
+			content_type: asset.content_type,
+			download_url: asset.browser_download_url.or(asset.path).unwrap_or_default(),
+			download_count: asset.download_count,
+		}
+	}
+}
+
+impl From<Issue> for IssueInfo {
+	fn from(issue: Issue) -> Self {
+		IssueInfo {
+			number: issue.number,
+			title: issue.title,
+			body: issue.body,
+			state: if issue.state == "closed" { IssueState::Closed } else { IssueState::Open },
+			labels: issue.labels.into_iter().map(|label| label.name).collect(),
+			assignees: issue.assignees.into_iter().map(|assignee| assignee.username).collect(),
+			milestone: None,
+			author: issue.author.map(|author| author.username),
+			pull_request: false,
+			created_at: issue.created_at,
+			updated_at: issue.updated_at,
+			closed_at: None,
+			raw: None,
+		}
+	}
+}
+
+impl From<Comment> for CommentInfo {
+	fn from(comment: Comment) -> Self {
+		CommentInfo {
+			id: comment.id,
+			body: comment.body,
+			author: comment.author.map(|author| author.username),
+			path: None,
+			line: None,
+			created_at: comment.created_at,
+			updated_at: comment.updated_at,
+			raw: None,
 		}
 	}
 }
@@ -1,42 +1,178 @@
 mod client;
 mod common;
 mod middleware;
+pub mod model;
 
-pub use nipaw_core::Client;
+pub use nipaw_core::{Client, ClientBuilder};
 
 use crate::{
-	client::{HTTP_CLIENT, PROXY_URL},
-	common::JsonValue,
+	client::build_http_client,
+	model::{
+		AssetUploadUrl, Branch, BranchDetail, Calendar, Comment, Commit, Issue, Org, Release, Repo,
+		Tag, User,
+	},
 };
 use async_trait::async_trait;
 use chrono::{Datelike, Local};
+use futures::stream::BoxStream;
 use nipaw_core::{
-	CollaboratorPermission, Result,
+	CollaboratorPermission, FromConfig, Result,
+	config::ClientConfig,
+	decode,
 	error::Error,
-	option::{CommitListOptions, OrgRepoListOptions, ReposListOptions},
+	metrics::Operation,
+	option::{
+		Affiliation, BranchListOptions, CommentListOptions, CommitListOptions, Direction,
+		IssueListOptions, IssueOptions, IssueSort, IssueStateFilter, IssueUpdateOptions,
+		OrgRepoListOptions, OrgRepoType, ReleaseListOptions, ReleaseOptions, ReleaseUpdateOptions,
+		RepoSort, RepoType, ReposListOptions, TagListOptions, VisibilityFilter,
+	},
+	pagination::{NextPage, PagedClient, error_stream},
 	types::{
+		branch::BranchInfo,
 		collaborator::CollaboratorResult,
+		comment::{CommentInfo, CommentTarget},
 		commit::CommitInfo,
+		issue::IssueInfo,
 		org::OrgInfo,
+		rate_limit::RateLimitInfo,
+		release::{AssetStream, AssetUpload, ReleaseAsset, ReleaseInfo},
 		repo::RepoInfo,
+		tag::TagInfo,
 		user::{ContributionResult, UserInfo},
 	},
 };
 use reqwest::{Url, header};
-use serde_json::Value;
+use reqwest_middleware::{ClientWithMiddleware, RequestBuilder};
 use std::collections::HashMap;
 
+const PLATFORM: &str = "CNB";
 const BASE_URL: &str = "https://cnb.cool";
 const API_URL: &str = "https://api.cnb.cool";
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone)]
 pub struct CnbClient {
-	pub token: Option<String>,
+	api_url: String,
+	base_url: String,
+	config: ClientConfig,
+	http: ClientWithMiddleware,
+}
+
+impl Default for CnbClient {
+	fn default() -> Self {
+		Self::new()
+	}
 }
 
 impl CnbClient {
 	pub fn new() -> Self {
-		Self::default()
+		Self::with_config(ClientConfig::default()).expect("default client config is valid")
+	}
+
+	/// 使用自定义配置创建客户端, 可用于对接私有化部署的 CNB 实例
+	///
+	/// # 参数
+	///
+	/// * `config` - 客户端配置, 详见 [ClientConfig]
+	pub fn with_config(config: ClientConfig) -> Result<Self> {
+		config.validate()?;
+		let base_urls = &config.base_urls;
+		Ok(Self {
+			api_url: base_urls.api_url_or(API_URL)?,
+			base_url: base_urls.base_url_or(BASE_URL)?,
+			http: build_http_client(&config)?,
+			config,
+		})
+	}
+
+	/// 获取当前客户端配置
+	pub fn config(&self) -> &ClientConfig {
+		&self.config
+	}
+
+	/// 替换客户端配置, 会重新创建该实例的 HTTP 客户端
+	///
+	/// # 参数
+	///
+	/// * `config` - 客户端配置, 详见 [ClientConfig]
+	pub fn set_config(&mut self, config: ClientConfig) -> Result<()> {
+		*self = Self::with_config(config)?;
+		Ok(())
+	}
+
+	/// 响应中的地址是否属于平台, 只向平台的地址发送访问令牌, 避免令牌泄露给对象存储等第三方服务
+	fn is_platform_url(&self, url: &str) -> bool {
+		let Ok(url) = Url::parse(url) else {
+			return false;
+		};
+		[&self.api_url, &self.base_url]
+			.into_iter()
+			.filter_map(|base| Url::parse(base).ok())
+			.any(|base| base.host_str() == url.host_str())
+	}
+
+	/// 按ID获取发布信息
+	async fn get_release(
+		&self,
+		repo_path: (&str, &str),
+		release_id: &str,
+		operation: &'static str,
+	) -> Result<ReleaseInfo> {
+		let url =
+			format!("{}/{}/{}/-/releases/{}", self.api_url, repo_path.0, repo_path.1, release_id);
+		let mut request = self.http.get(url).with_extension(Operation(operation));
+		if let Some(token) = &self.config.token {
+			request = request.bearer_auth(token);
+		}
+		let resp = request.send().await?;
+		decode::json_into::<Release, _>(resp, self.config.include_raw).await
+	}
+
+	/// 修改 Issue, `path` 为 Issue 下的子路径, 如 `/assignees`
+	async fn patch_issue(
+		&self,
+		repo_path: (&str, &str),
+		number: &str,
+		path: &str,
+		body: serde_json::Value,
+		operation: &'static str,
+	) -> Result<IssueInfo> {
+		let url =
+			format!("{}/{}/{}/-/issues/{}{}", self.api_url, repo_path.0, repo_path.1, number, path);
+		let mut request = self.http.patch(url).with_extension(Operation(operation));
+		if let Some(token) = &self.config.token {
+			request = request.bearer_auth(token);
+		}
+		let resp = request
+			.header(header::CONTENT_TYPE, "application/json")
+			.body(body.to_string())
+			.send()
+			.await?;
+		decode::json_into::<Issue, _>(resp, self.config.include_raw).await
+	}
+}
+
+impl PagedClient for CnbClient {
+	const PLATFORM: &'static str = PLATFORM;
+	const NEXT_PAGE: NextPage = NextPage::Len;
+	const PER_PAGE_PARAM: &'static str = "page_size";
+
+	fn page_request(&self, operation: &'static str, url: &str) -> RequestBuilder {
+		let mut request = self.http.get(url).with_extension(Operation(operation));
+		if let Some(token) = &self.config.token {
+			request = request.bearer_auth(token);
+		}
+		request
+	}
+
+	fn include_raw(&self) -> bool {
+		self.config.include_raw
+	}
+}
+
+impl FromConfig for CnbClient {
+	fn from_config(config: ClientConfig) -> Result<Self> {
+		Self::with_config(config)
 	}
 }
 
@@ -46,213 +182,211 @@ impl Client for CnbClient {
 		if token.is_empty() {
 			return Err(Error::TokenEmpty);
 		}
-		self.token = Some(token.to_string());
+		self.config.token = Some(token.to_string());
 		Ok(())
 	}
 
 	fn set_proxy(&mut self, proxy: &str) -> Result<()> {
-		PROXY_URL.set(proxy.to_string()).unwrap();
-		Ok(())
+		let mut config = self.config.clone();
+		config.proxy = Some(proxy.to_string());
+		self.set_config(config)
 	}
 
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
 	async fn get_user_info(&self) -> Result<UserInfo> {
-		if self.token.is_none() {
+		if self.config.token.is_none() {
 			return Err(Error::TokenEmpty);
 		}
-		let url = format!("{}/user", API_URL);
-		let request = HTTP_CLIENT
+		let url = format!("{}/user", self.api_url);
+		let request = self
+			.http
 			.get(url)
-			.header("Authorization", format!("Bearer {}", self.token.as_ref().unwrap()));
+			.with_extension(Operation("get_user_info"))
+			.header("Authorization", format!("Bearer {}", self.config.token.as_ref().unwrap()));
 		let resp = request.send().await?;
-		let mut user_info: JsonValue = resp.json().await?;
-
-		if let Some(username) = user_info.0.get("username").and_then(|v| v.as_str()) {
-			let avatar_url = self.get_user_avatar_url(username).await?;
-			user_info
-				.0
-				.as_object_mut()
-				.unwrap()
-				.insert("avatar_url".to_string(), Value::String(avatar_url));
-		}
-		Ok(user_info.into())
+		let mut user_info: UserInfo =
+			decode::json_into::<User, _>(resp, self.config.include_raw).await?;
+		user_info.avatar_url = self.get_user_avatar_url(&user_info.login).await?;
+		Ok(user_info)
 	}
 
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
 	async fn get_user_info_with_name(&self, user_name: &str) -> Result<UserInfo> {
-		let url = format!("{}/users/{}", API_URL, user_name);
-		let mut request = HTTP_CLIENT.get(url);
-		if let Some(token) = &self.token {
+		let url = format!("{}/users/{}", self.api_url, user_name);
+		let mut request = self.http.get(url).with_extension(Operation("get_user_info_with_name"));
+		if let Some(token) = &self.config.token {
 			request = request.header("Authorization", format!("Bearer {}", token));
 		}
 		let resp = request.send().await?;
-		let mut user_info: JsonValue = resp.json().await?;
-
-		if let Some(username) = user_info.0.get("username").and_then(|v| v.as_str()) {
-			let avatar_url = self.get_user_avatar_url(username).await?;
-			user_info
-				.0
-				.as_object_mut()
-				.unwrap()
-				.insert("avatar_url".to_string(), Value::String(avatar_url));
-		}
-		Ok(user_info.into())
+		let mut user_info: UserInfo =
+			decode::json_into::<User, _>(resp, self.config.include_raw).await?;
+		user_info.avatar_url = self.get_user_avatar_url(&user_info.login).await?;
+		Ok(user_info)
 	}
 
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
 	async fn get_user_avatar_url(&self, user_name: &str) -> Result<String> {
-		let url = format!("{}/users/{}/avatar/l", BASE_URL, user_name);
+		let url = format!("{}/users/{}/avatar/l", self.base_url, user_name);
 		Ok(url.to_string())
 	}
 
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
 	async fn get_user_contribution(&self, user_name: &str) -> Result<ContributionResult> {
-		let mut url = Url::parse(&format!("{}/users/{}/calendar", BASE_URL, user_name))?;
+		let mut url = Url::parse(&format!("{}/users/{}/calendar", self.base_url, user_name))?;
 		let year = Local::now().year();
 		url.query_pairs_mut().append_pair("year", &year.to_string());
-		let resp =
-			HTTP_CLIENT.get(url).header("Accept", " application/vnd.cnb.web+json").send().await?;
-		let contribution_result: JsonValue = resp.json().await?;
-		Ok(contribution_result.into())
+		let resp = self
+			.http
+			.get(url)
+			.with_extension(Operation("get_user_contribution"))
+			.header("Accept", " application/vnd.cnb.web+json")
+			.send()
+			.await?;
+		let calendar: Calendar = decode::json(resp).await?;
+		calendar.try_into()
 	}
 
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
 	async fn get_org_info(&self, org_name: &str) -> Result<OrgInfo> {
-		let url = format!("{}/{}", API_URL, org_name);
-		let mut request = HTTP_CLIENT.get(url);
-		if let Some(token) = &self.token {
+		let url = format!("{}/{}", self.api_url, org_name);
+		let mut request = self.http.get(url).with_extension(Operation("get_org_info"));
+		if let Some(token) = &self.config.token {
 			request = request.bearer_auth(token);
 		}
 		let resp = request.send().await?;
-		let mut org_info: JsonValue = resp.json().await?;
-
-		if let Some(username) = org_info.0.get("login").and_then(|v| v.as_str()) {
-			let avatar_url = self.get_user_avatar_url(username).await?;
-			org_info
-				.0
-				.as_object_mut()
-				.unwrap()
-				.insert("avatar_url".to_string(), Value::String(avatar_url));
-		}
-		Ok(org_info.into())
+		let mut org_info: OrgInfo =
+			decode::json_into::<Org, _>(resp, self.config.include_raw).await?;
+		org_info.avatar_url = self.get_user_avatar_url(&org_info.login).await?;
+		Ok(org_info)
 	}
 
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
 	async fn get_org_repos(
 		&self,
 		org_name: &str,
 		option: Option<OrgRepoListOptions>,
 	) -> Result<Vec<RepoInfo>> {
-		let url = format!("{}/{}/-/repos", API_URL, org_name);
-		let mut request = HTTP_CLIENT.get(url);
-		if let Some(token) = &self.token {
-			request = request.bearer_auth(token);
-		}
-		let mut params: HashMap<&str, String> = HashMap::new();
-		if let Some(option) = option {
-			let per_page = option.per_page.unwrap_or_default().min(100);
-			params.insert("per_page", per_page.to_string());
-			let page = option.page.unwrap_or_default();
-			params.insert("page", page.to_string());
+		let url = format!("{}/{}/-/repos", self.api_url, org_name);
+		let option = option.unwrap_or_default();
+		let params = org_repos_params(&option)?;
+		Ok(self.get_page::<Repo, _>("get_org_repos", &url, &params, option.timeout).await?.items)
+	}
+
+	fn get_org_repos_stream<'a>(
+		&'a self,
+		org_name: &'a str,
+		option: Option<OrgRepoListOptions>,
+		limit: Option<usize>,
+	) -> BoxStream<'a, Result<RepoInfo>> {
+		let url = format!("{}/{}/-/repos", self.api_url, org_name);
+		let option = option.unwrap_or_default();
+		match org_repos_params(&option) {
+			Ok(params) => self.paginate::<Repo, _>(
+				"get_org_repos_stream",
+				url,
+				params,
+				option.page(),
+				limit,
+				option.timeout,
+			),
+			Err(err) => error_stream(err),
 		}
-		let resp = request.send().await?;
-		let repo_infos: Vec<JsonValue> = resp.json().await?;
-		Ok(repo_infos.into_iter().map(|v| v.into()).collect())
 	}
 
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
 	async fn get_org_avatar_url(&self, org_name: &str) -> Result<String> {
-		let url = format!("{}/{}/-/logos/l", BASE_URL, org_name);
+		let url = format!("{}/{}/-/logos/l", self.base_url, org_name);
 		Ok(url.to_string())
 	}
 
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
 	async fn get_repo_info(&self, repo_path: (&str, &str)) -> Result<RepoInfo> {
-		let url = format!("{}/repos/{}/{}", API_URL, repo_path.0, repo_path.1);
-		let mut request = HTTP_CLIENT.get(url);
-		if let Some(token) = &self.token {
+		let url = format!("{}/repos/{}/{}", self.api_url, repo_path.0, repo_path.1);
+		let mut request = self.http.get(url).with_extension(Operation("get_repo_info"));
+		if let Some(token) = &self.config.token {
 			request = request.bearer_auth(token);
 		}
 		let resp = request.send().await?;
-		let mut repo_info: JsonValue = resp.json().await?;
-		let is_public = repo_info
-			.0
-			.get("visibility_level")
-			.and_then(|v| v.as_str())
-			.map(|s| s.to_lowercase() == "public")
-			.unwrap_or(false);
-		let default_branch = if is_public {
-			let url = format!(
-				"{}/repos/{}/{}/-/git/overview-branches?limit=5",
-				BASE_URL, repo_path.0, repo_path.1
-			);
-			let request = HTTP_CLIENT.get(url).header("Accept", "application/vnd.cnb.web+json");
-			let resp = request.send().await?;
-			let repo_info: JsonValue = resp.json().await?;
-			repo_info
-				.0
-				.get("default_branch")
-				.and_then(|v| v.get("name"))
-				.and_then(|v| v.as_str())
-				.map(|s| s.trim_start_matches("refs/heads/"))
-				.unwrap()
-				.to_string()
-		} else {
-			let url = format!("{}/repos/{}/{}/-/git/head", API_URL, repo_path.0, repo_path.1);
-			let mut request = HTTP_CLIENT.get(url);
-			if let Some(token) = &self.token {
-				request = request.bearer_auth(token);
-			}
-			let resp = request.send().await?;
-			let repo_info: JsonValue = resp.json().await?;
-			repo_info.0.get("name").and_then(|v| v.as_str()).unwrap().to_string()
-		};
-		repo_info
-			.0
-			.as_object_mut()
-			.unwrap()
-			.insert("default_branch".to_string(), Value::String(default_branch));
-		Ok(repo_info.into())
-	}
-
-	async fn get_user_repos(&self, option: Option<ReposListOptions>) -> Result<Vec<RepoInfo>> {
-		let url = format!("{}/user/repos", API_URL);
-		let mut request = HTTP_CLIENT.get(url);
-		let mut params: HashMap<&str, String> = HashMap::new();
-		if let Some(token) = &self.token {
+		let (repo, raw) = decode::json_with_raw::<Repo>(resp, self.config.include_raw).await?;
+		// 仓库详情不返回默认分支, 从 HEAD 接口获取
+		let url = format!("{}/repos/{}/{}/-/git/head", self.api_url, repo_path.0, repo_path.1);
+		let mut request = self.http.get(url).with_extension(Operation("get_repo_info"));
+		if let Some(token) = &self.config.token {
 			request = request.bearer_auth(token);
 		}
+		let resp = request.send().await?;
+		let head: Branch = decode::json(resp).await?;
+		let mut repo_info: RepoInfo = repo.into();
+		repo_info.raw = raw;
+		repo_info.default_branch = head.name;
+		Ok(repo_info)
+	}
 
-		params.insert("sort", "pushed".to_owned());
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn get_user_repos(&self, option: Option<ReposListOptions>) -> Result<Vec<RepoInfo>> {
+		let url = format!("{}/user/repos", self.api_url);
+		let option = option.unwrap_or_default();
+		let params = repos_params(&option, None)?;
+		Ok(self.get_page::<Repo, _>("get_user_repos", &url, &params, option.timeout).await?.items)
+	}
 
-		if let Some(option) = option {
-			let per_page = option.per_page.unwrap_or_default().min(100);
-			params.insert("per_page", per_page.to_string());
-			let page = option.page.unwrap_or_default();
-			params.insert("page", page.to_string());
+	fn get_user_repos_stream(
+		&self,
+		option: Option<ReposListOptions>,
+		limit: Option<usize>,
+	) -> BoxStream<'_, Result<RepoInfo>> {
+		let url = format!("{}/user/repos", self.api_url);
+		let option = option.unwrap_or_default();
+		match repos_params(&option, None) {
+			Ok(params) => self.paginate::<Repo, _>(
+				"get_user_repos_stream",
+				url,
+				params,
+				option.page(),
+				limit,
+				option.timeout,
+			),
+			Err(err) => error_stream(err),
 		}
-		let resp = request.query(&params).send().await?;
-		let repo_infos: Vec<JsonValue> = resp.json().await?;
-		Ok(repo_infos.into_iter().map(|v| v.into()).collect())
 	}
 
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
 	async fn get_user_repos_with_name(
 		&self,
 		user_name: &str,
 		option: Option<ReposListOptions>,
 	) -> Result<Vec<RepoInfo>> {
-		let url = format!("{}/users/{}/repos", API_URL, user_name);
-		let mut request = HTTP_CLIENT.get(url);
-		let mut params: HashMap<&str, String> = HashMap::new();
-		if let Some(token) = &self.token {
-			request = request.bearer_auth(token);
-		}
-
-		params.insert("role", "owner".to_owned());
+		let url = format!("{}/users/{}/repos", self.api_url, user_name);
+		let option = option.unwrap_or_default();
+		let params = repos_params(&option, Some(Affiliation::Owner))?;
+		Ok(self
+			.get_page::<Repo, _>("get_user_repos_with_name", &url, &params, option.timeout)
+			.await?
+			.items)
+	}
 
-		if let Some(option) = option {
-			let per_page = option.per_page.unwrap_or_default().min(100);
-			params.insert("per_page", per_page.to_string());
-			let page = option.page.unwrap_or_default();
-			params.insert("page", page.to_string());
+	fn get_user_repos_with_name_stream<'a>(
+		&'a self,
+		user_name: &'a str,
+		option: Option<ReposListOptions>,
+		limit: Option<usize>,
+	) -> BoxStream<'a, Result<RepoInfo>> {
+		let url = format!("{}/users/{}/repos", self.api_url, user_name);
+		let option = option.unwrap_or_default();
+		match repos_params(&option, Some(Affiliation::Owner)) {
+			Ok(params) => self.paginate::<Repo, _>(
+				"get_user_repos_with_name_stream",
+				url,
+				params,
+				option.page(),
+				limit,
+				option.timeout,
+			),
+			Err(err) => error_stream(err),
 		}
-		let resp = request.query(&params).send().await?;
-		let repo_infos: Vec<JsonValue> = resp.json().await?;
-		Ok(repo_infos.into_iter().map(|v| v.into()).collect())
 	}
 
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
 	async fn get_commit_info(
 		&self,
 		repo_path: (&str, &str),
@@ -260,104 +394,68 @@ impl Client for CnbClient {
 	) -> Result<CommitInfo> {
 		let url = format!(
 			"{}/{}/{}/-/git/commits/{}",
-			API_URL,
+			self.api_url,
 			repo_path.0,
 			repo_path.1,
 			sha.unwrap_or("HEAD")
 		);
-		let mut request = HTTP_CLIENT.get(url);
-		if let Some(token) = &self.token {
+		let mut request = self.http.get(url).with_extension(Operation("get_commit_info"));
+		if let Some(token) = &self.config.token {
 			request = request.bearer_auth(token);
 		}
 		let resp = request.send().await?;
-		let mut commit_info: JsonValue = resp.json().await?;
-		let author_name = commit_info
-			.0
-			.get("commit")
-			.and_then(|commit| commit.as_object())
-			.and_then(|commit_obj| commit_obj.get("author"))
-			.and_then(|author| author.as_object())
-			.and_then(|author_obj| author_obj.get("name"))
-			.and_then(|name| name.as_str())
-			.unwrap()
-			.to_string();
-
-		let committer_name = commit_info
-			.0
-			.get("commit")
-			.and_then(|commit| commit.as_object())
-			.and_then(|commit_obj| commit_obj.get("committer"))
-			.and_then(|committer| committer.as_object())
-			.and_then(|committer_obj| committer_obj.get("name"))
-			.and_then(|name| name.as_str())
-			.unwrap()
-			.to_string();
-
-		if let Some(author) = commit_info
-			.0
-			.get_mut("commit")
-			.and_then(|commit| commit.as_object_mut())
-			.and_then(|commit_obj| commit_obj.get_mut("author"))
-			.and_then(|author| author.as_object_mut())
-		{
-			let avatar_url = self.get_user_avatar_url(author_name.as_str()).await?;
-			author.insert("avatar_url".to_string(), Value::String(avatar_url));
-		}
-		if let Some(committer) = commit_info
-			.0
-			.get_mut("commit")
-			.and_then(|commit| commit.as_object_mut())
-			.and_then(|commit_obj| commit_obj.get_mut("committer"))
-			.and_then(|committer| committer.as_object_mut())
-		{
-			let avatar_url = self.get_user_avatar_url(committer_name.as_str()).await?;
-			committer.insert("avatar_url".to_string(), Value::String(avatar_url));
-		}
-		Ok(commit_info.into())
+		let mut commit_info: CommitInfo =
+			decode::json_into::<Commit, _>(resp, self.config.include_raw).await?;
+		let author = &mut commit_info.commit.author;
+		author.avatar_url = self.get_user_avatar_url(&author.name).await?;
+		let committer = &mut commit_info.commit.committer;
+		committer.avatar_url = self.get_user_avatar_url(&committer.name).await?;
+		Ok(commit_info)
 	}
 
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
 	async fn get_commit_infos(
 		&self,
 		repo_path: (&str, &str),
 		option: Option<CommitListOptions>,
 	) -> Result<Vec<CommitInfo>> {
-		let url = format!("{}/{}/{}/-/commits", API_URL, repo_path.0, repo_path.1);
-		let mut request = HTTP_CLIENT.get(url);
-		if let Some(token) = &self.token {
-			request = request.bearer_auth(token);
-		}
-		let mut params: HashMap<&str, String> = HashMap::new();
-		if let Some(option) = option {
-			let per_page = option.per_page.unwrap_or_default().min(100);
-			params.insert("per_page", per_page.to_string());
-			let page = option.page.unwrap_or_default();
-			params.insert("page", page.to_string());
-			if let Some(sha) = option.sha {
-				params.insert("sha", sha.to_string());
-			}
-			if let Some(author) = option.author {
-				params.insert("author", author.to_string());
-			}
-			if let Some(since) = option.since {
-				params.insert("since", since.to_rfc3339());
-			}
-			if let Some(until) = option.until {
-				params.insert("until", until.to_rfc3339());
-			}
-		}
-		let resp = request.query(&params).send().await?;
-		let commit_infos: Vec<JsonValue> = resp.json().await?;
-		Ok(commit_infos.into_iter().map(|v| v.into()).collect())
+		let url = format!("{}/{}/{}/-/commits", self.api_url, repo_path.0, repo_path.1);
+		let option = option.unwrap_or_default();
+		let params = commits_params(&option);
+		Ok(self
+			.get_page::<Commit, _>("get_commit_infos", &url, &params, option.timeout)
+			.await?
+			.items)
+	}
+
+	fn get_commit_infos_stream<'a>(
+		&'a self,
+		repo_path: (&'a str, &'a str),
+		option: Option<CommitListOptions>,
+		limit: Option<usize>,
+	) -> BoxStream<'a, Result<CommitInfo>> {
+		let url = format!("{}/{}/{}/-/commits", self.api_url, repo_path.0, repo_path.1);
+		let option = option.unwrap_or_default();
+		self.paginate::<Commit, _>(
+			"get_commit_infos_stream",
+			url,
+			commits_params(&option),
+			option.page(),
+			limit,
+			option.timeout,
+		)
 	}
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
 	async fn add_repo_collaborator(
 		&self,
 		repo_path: (&str, &str),
 		user_name: &str,
 		permission: Option<CollaboratorPermission>,
 	) -> Result<CollaboratorResult> {
-		let url = format!("{}/{}/{}/-/members/{}", API_URL, repo_path.0, repo_path.1, user_name);
-		let mut request = HTTP_CLIENT.post(url);
-		if let Some(token) = &self.token {
+		let url =
+			format!("{}/{}/{}/-/members/{}", self.api_url, repo_path.0, repo_path.1, user_name);
+		let mut request = self.http.post(url).with_extension(Operation("add_repo_collaborator"));
+		if let Some(token) = &self.config.token {
 			request = request.bearer_auth(token);
 		}
 		let permission = match permission {
@@ -373,20 +471,884 @@ impl Client for CnbClient {
 			"access_level": permission.to_string(),
 			"is_outside_collaborator": true,
 		});
+		// 非 2xx 响应已由中间件转换为错误, 成功时响应体不含成员信息
+		request
+			.header(header::CONTENT_TYPE, "application/json")
+			.body(body.to_string())
+			.send()
+			.await?;
+		Ok(CollaboratorResult {
+			login: user_name.to_string(),
+			avatar_url: self.get_user_avatar_url(user_name).await?,
+		})
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn get_branches(
+		&self,
+		repo_path: (&str, &str),
+		option: Option<BranchListOptions>,
+	) -> Result<Vec<BranchInfo>> {
+		let url = format!("{}/{}/{}/-/git/branches", self.api_url, repo_path.0, repo_path.1);
+		let option = option.unwrap_or_default();
+		let params = branches_params(&option);
+		Ok(self
+			.get_page::<BranchDetail, _>("get_branches", &url, &params, option.timeout)
+			.await?
+			.items)
+	}
+
+	fn get_branches_stream<'a>(
+		&'a self,
+		repo_path: (&'a str, &'a str),
+		option: Option<BranchListOptions>,
+		limit: Option<usize>,
+	) -> BoxStream<'a, Result<BranchInfo>> {
+		let url = format!("{}/{}/{}/-/git/branches", self.api_url, repo_path.0, repo_path.1);
+		let option = option.unwrap_or_default();
+		self.paginate::<BranchDetail, _>(
+			"get_branches_stream",
+			url,
+			branches_params(&option),
+			option.page(),
+			limit,
+			option.timeout,
+		)
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn get_branch(&self, repo_path: (&str, &str), branch: &str) -> Result<BranchInfo> {
+		let url =
+			format!("{}/{}/{}/-/git/branches/{}", self.api_url, repo_path.0, repo_path.1, branch);
+		let mut request = self.http.get(url).with_extension(Operation("get_branch"));
+		if let Some(token) = &self.config.token {
+			request = request.bearer_auth(token);
+		}
+		let resp = request.send().await?;
+		decode::json_into::<BranchDetail, _>(resp, self.config.include_raw).await
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn create_branch(
+		&self,
+		repo_path: (&str, &str),
+		branch: &str,
+		from: &str,
+	) -> Result<BranchInfo> {
+		let url = format!("{}/{}/{}/-/git/branches", self.api_url, repo_path.0, repo_path.1);
+		let mut request = self.http.post(url).with_extension(Operation("create_branch"));
+		if let Some(token) = &self.config.token {
+			request = request.bearer_auth(token);
+		}
+		let body = serde_json::json!({
+			"name": branch,
+			"start_point": from,
+		});
+		// 创建成功时响应体不含分支信息
+		request
+			.header(header::CONTENT_TYPE, "application/json")
+			.body(body.to_string())
+			.send()
+			.await?;
+		self.get_branch(repo_path, branch).await
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn delete_branch(&self, repo_path: (&str, &str), branch: &str) -> Result<()> {
+		let url =
+			format!("{}/{}/{}/-/git/branches/{}", self.api_url, repo_path.0, repo_path.1, branch);
+		let mut request = self.http.delete(url).with_extension(Operation("delete_branch"));
+		if let Some(token) = &self.config.token {
+			request = request.bearer_auth(token);
+		}
+		request.send().await?;
+		Ok(())
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn get_tags(
+		&self,
+		repo_path: (&str, &str),
+		option: Option<TagListOptions>,
+	) -> Result<Vec<TagInfo>> {
+		let url = format!("{}/{}/{}/-/git/tags", self.api_url, repo_path.0, repo_path.1);
+		let option = option.unwrap_or_default();
+		let params = tags_params(&option);
+		Ok(self.get_page::<Tag, _>("get_tags", &url, &params, option.timeout).await?.items)
+	}
+
+	fn get_tags_stream<'a>(
+		&'a self,
+		repo_path: (&'a str, &'a str),
+		option: Option<TagListOptions>,
+		limit: Option<usize>,
+	) -> BoxStream<'a, Result<TagInfo>> {
+		let url = format!("{}/{}/{}/-/git/tags", self.api_url, repo_path.0, repo_path.1);
+		let option = option.unwrap_or_default();
+		self.paginate::<Tag, _>(
+			"get_tags_stream",
+			url,
+			tags_params(&option),
+			option.page(),
+			limit,
+			option.timeout,
+		)
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn get_tag(&self, repo_path: (&str, &str), tag: &str) -> Result<TagInfo> {
+		let url = format!("{}/{}/{}/-/git/tags/{}", self.api_url, repo_path.0, repo_path.1, tag);
+		let mut request = self.http.get(url).with_extension(Operation("get_tag"));
+		if let Some(token) = &self.config.token {
+			request = request.bearer_auth(token);
+		}
+		let resp = request.send().await?;
+		decode::json_into::<Tag, _>(resp, self.config.include_raw).await
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn create_tag(
+		&self,
+		repo_path: (&str, &str),
+		tag: &str,
+		target: &str,
+		message: Option<&str>,
+	) -> Result<TagInfo> {
+		let url = format!("{}/{}/{}/-/git/tags", self.api_url, repo_path.0, repo_path.1);
+		let mut request = self.http.post(url).with_extension(Operation("create_tag"));
+		if let Some(token) = &self.config.token {
+			request = request.bearer_auth(token);
+		}
+		let mut body = serde_json::json!({
+			"name": tag,
+			"target": target,
+		});
+		if let Some(message) = message {
+			body["message"] = message.into();
+		}
+		request
+			.header(header::CONTENT_TYPE, "application/json")
+			.body(body.to_string())
+			.send()
+			.await?;
+		self.get_tag(repo_path, tag).await
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn delete_tag(&self, repo_path: (&str, &str), tag: &str) -> Result<()> {
+		let url = format!("{}/{}/{}/-/git/tags/{}", self.api_url, repo_path.0, repo_path.1, tag);
+		let mut request = self.http.delete(url).with_extension(Operation("delete_tag"));
+		if let Some(token) = &self.config.token {
+			request = request.bearer_auth(token);
+		}
+		request.send().await?;
+		Ok(())
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn get_releases(
+		&self,
+		repo_path: (&str, &str),
+		option: Option<ReleaseListOptions>,
+	) -> Result<Vec<ReleaseInfo>> {
+		let url = format!("{}/{}/{}/-/releases", self.api_url, repo_path.0, repo_path.1);
+		let option = option.unwrap_or_default();
+		let params = releases_params(&option);
+		Ok(self.get_page::<Release, _>("get_releases", &url, &params, option.timeout).await?.items)
+	}
+
+	fn get_releases_stream<'a>(
+		&'a self,
+		repo_path: (&'a str, &'a str),
+		option: Option<ReleaseListOptions>,
+		limit: Option<usize>,
+	) -> BoxStream<'a, Result<ReleaseInfo>> {
+		let url = format!("{}/{}/{}/-/releases", self.api_url, repo_path.0, repo_path.1);
+		let option = option.unwrap_or_default();
+		self.paginate::<Release, _>(
+			"get_releases_stream",
+			url,
+			releases_params(&option),
+			option.page(),
+			limit,
+			option.timeout,
+		)
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn get_latest_release(&self, repo_path: (&str, &str)) -> Result<ReleaseInfo> {
+		let url = format!("{}/{}/{}/-/releases/latest", self.api_url, repo_path.0, repo_path.1);
+		let mut request = self.http.get(url).with_extension(Operation("get_latest_release"));
+		if let Some(token) = &self.config.token {
+			request = request.bearer_auth(token);
+		}
+		let resp = request.send().await?;
+		decode::json_into::<Release, _>(resp, self.config.include_raw).await
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn get_release_by_tag(&self, repo_path: (&str, &str), tag: &str) -> Result<ReleaseInfo> {
+		let url =
+			format!("{}/{}/{}/-/releases/tags/{}", self.api_url, repo_path.0, repo_path.1, tag);
+		let mut request = self.http.get(url).with_extension(Operation("get_release_by_tag"));
+		if let Some(token) = &self.config.token {
+			request = request.bearer_auth(token);
+		}
+		let resp = request.send().await?;
+		decode::json_into::<Release, _>(resp, self.config.include_raw).await
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn create_release(
+		&self,
+		repo_path: (&str, &str),
+		option: ReleaseOptions,
+	) -> Result<ReleaseInfo> {
+		let tag_name = option.tag_name.clone();
+		let url = format!("{}/{}/{}/-/releases", self.api_url, repo_path.0, repo_path.1);
+		let mut request = self.http.post(url).with_extension(Operation("create_release"));
+		if let Some(token) = &self.config.token {
+			request = request.bearer_auth(token);
+		}
+		request
+			.header(header::CONTENT_TYPE, "application/json")
+			.body(release_body(&option.into()).to_string())
+			.send()
+			.await?;
+		self.get_release_by_tag(repo_path, &tag_name).await
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn update_release(
+		&self,
+		repo_path: (&str, &str),
+		release_id: &str,
+		option: ReleaseUpdateOptions,
+	) -> Result<ReleaseInfo> {
+		let url =
+			format!("{}/{}/{}/-/releases/{}", self.api_url, repo_path.0, repo_path.1, release_id);
+		let mut request = self.http.patch(url).with_extension(Operation("update_release"));
+		if let Some(token) = &self.config.token {
+			request = request.bearer_auth(token);
+		}
+		// 更新成功时响应体不含发布信息
+		request
+			.header(header::CONTENT_TYPE, "application/json")
+			.body(release_body(&option).to_string())
+			.send()
+			.await?;
+		self.get_release(repo_path, release_id, "update_release").await
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn delete_release(&self, repo_path: (&str, &str), release_id: &str) -> Result<()> {
+		let url =
+			format!("{}/{}/{}/-/releases/{}", self.api_url, repo_path.0, repo_path.1, release_id);
+		let mut request = self.http.delete(url).with_extension(Operation("delete_release"));
+		if let Some(token) = &self.config.token {
+			request = request.bearer_auth(token);
+		}
+		request.send().await?;
+		Ok(())
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn upload_release_asset(
+		&self,
+		repo_path: (&str, &str),
+		release_id: &str,
+		asset: AssetUpload,
+	) -> Result<ReleaseAsset> {
+		// 先申请对象存储的上传地址, 上传完成后再通知平台校验
+		let url = format!(
+			"{}/{}/{}/-/releases/{}/asset-upload-url",
+			self.api_url, repo_path.0, repo_path.1, release_id
+		);
+		let mut request = self.http.post(url).with_extension(Operation("upload_release_asset"));
+		if let Some(token) = &self.config.token {
+			request = request.bearer_auth(token);
+		}
+		let body = serde_json::json!({
+			"asset_name": asset.name,
+			"size": asset.size,
+			"overwrite": true,
+		});
 		let resp = request
 			.header(header::CONTENT_TYPE, "application/json")
 			.body(body.to_string())
 			.send()
 			.await?;
-		let status_code = resp.status().as_u16();
-		if status_code == 200 {
-			let collaborator = CollaboratorResult {
-				login: user_name.to_string(),
-				avatar_url: self.get_user_avatar_url(user_name).await?,
-			};
-			Ok(collaborator)
+		let upload: AssetUploadUrl = decode::json(resp).await?;
+
+		self.http
+			.put(upload.upload_url)
+			.with_extension(Operation("upload_release_asset"))
+			.header(header::CONTENT_TYPE, asset.mime())
+			.header(header::CONTENT_LENGTH, asset.size)
+			.body(asset.body)
+			.send()
+			.await?;
+
+		let mut request =
+			self.http.post(&upload.verify_url).with_extension(Operation("upload_release_asset"));
+		if let Some(token) = &self.config.token
+			&& self.is_platform_url(&upload.verify_url)
+		{
+			request = request.bearer_auth(token);
+		}
+		request.send().await?;
+
+		self.get_release(repo_path, release_id, "upload_release_asset")
+			.await?
+			.assets
+			.into_iter()
+			.find(|uploaded| uploaded.name == asset.name)
+			.ok_or(Error::NotFound)
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn download_release_asset(
+		&self,
+		repo_path: (&str, &str),
+		release_id: &str,
+		asset_id: &str,
+	) -> Result<AssetStream> {
+		let asset = self
+			.get_release(repo_path, release_id, "download_release_asset")
+			.await?
+			.assets
+			.into_iter()
+			.find(|asset| asset.id == asset_id)
+			.ok_or(Error::NotFound)?;
+		// 下载地址可能是相对于网页端的路径
+		let url = if asset.download_url.starts_with('/') {
+			format!("{}{}", self.base_url, asset.download_url)
 		} else {
-			Err(Error::NotFound)
+			asset.download_url
+		};
+		let mut request = self.http.get(&url).with_extension(Operation("download_release_asset"));
+		if let Some(token) = &self.config.token
+			&& self.is_platform_url(&url)
+		{
+			request = request.bearer_auth(token);
+		}
+		let resp = request.send().await?;
+		Ok(decode::bytes_stream(resp))
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn delete_release_asset(
+		&self,
+		repo_path: (&str, &str),
+		release_id: &str,
+		asset_id: &str,
+	) -> Result<()> {
+		let url = format!(
+			"{}/{}/{}/-/releases/{}/assets/{}",
+			self.api_url, repo_path.0, repo_path.1, release_id, asset_id
+		);
+		let mut request = self.http.delete(url).with_extension(Operation("delete_release_asset"));
+		if let Some(token) = &self.config.token {
+			request = request.bearer_auth(token);
+		}
+		request.send().await?;
+		Ok(())
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn get_issues(
+		&self,
+		repo_path: (&str, &str),
+		option: Option<IssueListOptions>,
+	) -> Result<Vec<IssueInfo>> {
+		let url = format!("{}/{}/{}/-/issues", self.api_url, repo_path.0, repo_path.1);
+		let option = option.unwrap_or_default();
+		let params = issues_params(&option)?;
+		Ok(self.get_page::<Issue, _>("get_issues", &url, &params, option.timeout).await?.items)
+	}
+
+	fn get_issues_stream<'a>(
+		&'a self,
+		repo_path: (&'a str, &'a str),
+		option: Option<IssueListOptions>,
+		limit: Option<usize>,
+	) -> BoxStream<'a, Result<IssueInfo>> {
+		let url = format!("{}/{}/{}/-/issues", self.api_url, repo_path.0, repo_path.1);
+		let option = option.unwrap_or_default();
+		match issues_params(&option) {
+			Ok(params) => self.paginate::<Issue, _>(
+				"get_issues_stream",
+				url,
+				params,
+				option.page(),
+				limit,
+				option.timeout,
+			),
+			Err(err) => error_stream(err),
+		}
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn get_issue(&self, repo_path: (&str, &str), number: &str) -> Result<IssueInfo> {
+		let url = format!("{}/{}/{}/-/issues/{}", self.api_url, repo_path.0, repo_path.1, number);
+		let mut request = self.http.get(url).with_extension(Operation("get_issue"));
+		if let Some(token) = &self.config.token {
+			request = request.bearer_auth(token);
+		}
+		let resp = request.send().await?;
+		decode::json_into::<Issue, _>(resp, self.config.include_raw).await
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn create_issue(
+		&self,
+		repo_path: (&str, &str),
+		option: IssueOptions,
+	) -> Result<IssueInfo> {
+		if option.milestone.is_some() {
+			return Err(unsupported("milestone".to_string()));
+		}
+		let mut body = serde_json::json!({ "title": option.title });
+		if let Some(issue_body) = &option.body {
+			body["body"] = issue_body.as_str().into();
 		}
+		if let Some(labels) = &option.labels {
+			body["labels"] = labels.clone().into();
+		}
+		if let Some(assignees) = &option.assignees {
+			body["assignees"] = assignees.clone().into();
+		}
+		let url = format!("{}/{}/{}/-/issues", self.api_url, repo_path.0, repo_path.1);
+		let mut request = self.http.post(url).with_extension(Operation("create_issue"));
+		if let Some(token) = &self.config.token {
+			request = request.bearer_auth(token);
+		}
+		let resp = request
+			.header(header::CONTENT_TYPE, "application/json")
+			.body(body.to_string())
+			.send()
+			.await?;
+		decode::json_into::<Issue, _>(resp, self.config.include_raw).await
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn update_issue(
+		&self,
+		repo_path: (&str, &str),
+		number: &str,
+		option: IssueUpdateOptions,
+	) -> Result<IssueInfo> {
+		if option.milestone.is_some() {
+			return Err(unsupported("milestone".to_string()));
+		}
+		let mut issue = None;
+		if option.title.is_some() || option.body.is_some() {
+			let mut body = serde_json::json!({});
+			if let Some(title) = &option.title {
+				body["title"] = title.as_str().into();
+			}
+			if let Some(issue_body) = &option.body {
+				body["body"] = issue_body.as_str().into();
+			}
+			issue = Some(self.patch_issue(repo_path, number, "", body, "update_issue").await?);
+		}
+		// 标签和处理人需要通过单独的接口修改
+		if let Some(issue) = issue
+			&& option.labels.is_none()
+			&& option.assignees.is_none()
+		{
+			return Ok(issue);
+		}
+		if let Some(labels) = option.labels {
+			let url = format!(
+				"{}/{}/{}/-/issues/{}/labels",
+				self.api_url, repo_path.0, repo_path.1, number
+			);
+			let mut request = self.http.put(url).with_extension(Operation("update_issue"));
+			if let Some(token) = &self.config.token {
+				request = request.bearer_auth(token);
+			}
+			request
+				.header(header::CONTENT_TYPE, "application/json")
+				.body(serde_json::json!({ "labels": labels }).to_string())
+				.send()
+				.await?;
+		}
+		if let Some(assignees) = option.assignees {
+			let body = serde_json::json!({ "assignees": assignees });
+			self.patch_issue(repo_path, number, "/assignees", body, "update_issue").await?;
+		}
+		self.get_issue(repo_path, number).await
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn close_issue(&self, repo_path: (&str, &str), number: &str) -> Result<IssueInfo> {
+		let body = serde_json::json!({ "state": "closed", "state_reason": "completed" });
+		self.patch_issue(repo_path, number, "", body, "close_issue").await
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn lock_issue(&self, _repo_path: (&str, &str), _number: &str) -> Result<()> {
+		Err(unsupported("lock_issue".to_string()))
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn unlock_issue(&self, _repo_path: (&str, &str), _number: &str) -> Result<()> {
+		Err(unsupported("unlock_issue".to_string()))
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn get_comments(
+		&self,
+		repo_path: (&str, &str),
+		target: CommentTarget,
+		number: &str,
+		option: Option<CommentListOptions>,
+	) -> Result<Vec<CommentInfo>> {
+		let url = format!(
+			"{}/{}/{}/-/{}/{}/comments",
+			self.api_url,
+			repo_path.0,
+			repo_path.1,
+			comment_path(target),
+			number
+		);
+		let option = option.unwrap_or_default();
+		let params = comments_params(&option);
+		Ok(self.get_page::<Comment, _>("get_comments", &url, &params, option.timeout).await?.items)
+	}
+
+	fn get_comments_stream<'a>(
+		&'a self,
+		repo_path: (&'a str, &'a str),
+		target: CommentTarget,
+		number: &'a str,
+		option: Option<CommentListOptions>,
+		limit: Option<usize>,
+	) -> BoxStream<'a, Result<CommentInfo>> {
+		let url = format!(
+			"{}/{}/{}/-/{}/{}/comments",
+			self.api_url,
+			repo_path.0,
+			repo_path.1,
+			comment_path(target),
+			number
+		);
+		let option = option.unwrap_or_default();
+		self.paginate::<Comment, _>(
+			"get_comments_stream",
+			url,
+			comments_params(&option),
+			option.page(),
+			limit,
+			option.timeout,
+		)
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn get_review_comments(
+		&self,
+		_repo_path: (&str, &str),
+		_number: &str,
+		_option: Option<CommentListOptions>,
+	) -> Result<Vec<CommentInfo>> {
+		Err(unsupported("get_review_comments".to_string()))
+	}
+
+	fn get_review_comments_stream<'a>(
+		&'a self,
+		_repo_path: (&'a str, &'a str),
+		_number: &'a str,
+		_option: Option<CommentListOptions>,
+		_limit: Option<usize>,
+	) -> BoxStream<'a, Result<CommentInfo>> {
+		error_stream(unsupported("get_review_comments_stream".to_string()))
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn create_comment(
+		&self,
+		repo_path: (&str, &str),
+		target: CommentTarget,
+		number: &str,
+		body: &str,
+	) -> Result<CommentInfo> {
+		let url = format!(
+			"{}/{}/{}/-/{}/{}/comments",
+			self.api_url,
+			repo_path.0,
+			repo_path.1,
+			comment_path(target),
+			number
+		);
+		let mut request = self.http.post(url).with_extension(Operation("create_comment"));
+		if let Some(token) = &self.config.token {
+			request = request.bearer_auth(token);
+		}
+		let resp = request
+			.header(header::CONTENT_TYPE, "application/json")
+			.body(serde_json::json!({ "body": body }).to_string())
+			.send()
+			.await?;
+		decode::json_into::<Comment, _>(resp, self.config.include_raw).await
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn update_comment(
+		&self,
+		repo_path: (&str, &str),
+		target: CommentTarget,
+		number: &str,
+		comment_id: &str,
+		body: &str,
+	) -> Result<CommentInfo> {
+		let url = format!(
+			"{}/{}/{}/-/{}/{}/comments/{}",
+			self.api_url,
+			repo_path.0,
+			repo_path.1,
+			comment_path(target),
+			number,
+			comment_id
+		);
+		let mut request = self.http.patch(url).with_extension(Operation("update_comment"));
+		if let Some(token) = &self.config.token {
+			request = request.bearer_auth(token);
+		}
+		let resp = request
+			.header(header::CONTENT_TYPE, "application/json")
+			.body(serde_json::json!({ "body": body }).to_string())
+			.send()
+			.await?;
+		decode::json_into::<Comment, _>(resp, self.config.include_raw).await
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn delete_comment(
+		&self,
+		_repo_path: (&str, &str),
+		_target: CommentTarget,
+		_number: &str,
+		_comment_id: &str,
+	) -> Result<()> {
+		Err(unsupported("delete_comment".to_string()))
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn get_rate_limit(&self) -> Result<RateLimitInfo> {
+		if self.config.token.is_none() {
+			return Err(Error::TokenEmpty);
+		}
+		let url = format!("{}/user", self.api_url);
+		let request = self
+			.http
+			.get(url)
+			.with_extension(Operation("get_rate_limit"))
+			.header("Authorization", format!("Bearer {}", self.config.token.as_ref().unwrap()));
+		RateLimitInfo::from_result(request.send().await)
+	}
+}
+
+fn org_repos_params(option: &OrgRepoListOptions) -> Result<HashMap<&'static str, String>> {
+	let mut params = HashMap::new();
+	params.insert("page_size", option.per_page().to_string());
+	params.insert("page", option.page().to_string());
+	match option.repo_type {
+		None | Some(OrgRepoType::All) => {}
+		Some(repo_type @ (OrgRepoType::Public | OrgRepoType::Private)) => {
+			params.insert("filter_type", repo_type.to_string());
+		}
+		Some(repo_type) => return Err(unsupported(format!("type={repo_type}"))),
+	}
+	if let Some(sort) = option.sort {
+		params.insert("order_by", order_by(sort)?.to_string());
+	}
+	if let Some(direction) = option.direction {
+		params.insert("desc", (direction == Direction::Desc).to_string());
+	}
+	Ok(params)
+}
+
+fn unsupported(option: String) -> Error {
+	Error::Unsupported { platform: PLATFORM, option }
+}
+
+fn repos_params(
+	option: &ReposListOptions,
+	default_affiliation: Option<Affiliation>,
+) -> Result<HashMap<&'static str, String>> {
+	let mut params = HashMap::new();
+	params.insert("page_size", option.per_page().to_string());
+	params.insert("page", option.page().to_string());
+	match option.visibility {
+		None | Some(VisibilityFilter::All) => {}
+		Some(visibility) => {
+			params.insert("filter_type", visibility.to_string());
+		}
+	}
+	match option.affiliation.or(default_affiliation) {
+		None => {}
+		Some(Affiliation::Owner) => {
+			params.insert("role", "owner".to_string());
+		}
+		Some(affiliation) => return Err(unsupported(format!("affiliation={affiliation}"))),
+	}
+	match option.repo_type {
+		None | Some(RepoType::All) => {}
+		Some(repo_type) => return Err(unsupported(format!("type={repo_type}"))),
+	}
+	if let Some(sort) = option.sort {
+		params.insert("order_by", order_by(sort)?.to_string());
+	}
+	if let Some(direction) = option.direction {
+		params.insert("desc", (direction == Direction::Desc).to_string());
+	}
+	if let Some(search) = &option.search {
+		params.insert("search", search.to_string());
+	}
+	Ok(params)
+}
+
+fn order_by(sort: RepoSort) -> Result<&'static str> {
+	match sort {
+		RepoSort::Created => Ok("created_at"),
+		RepoSort::Updated => Ok("last_updated_at"),
+		RepoSort::FullName => Ok("slug_path"),
+		RepoSort::Pushed => Err(unsupported(format!("sort={sort}"))),
+	}
+}
+
+fn commits_params(option: &CommitListOptions) -> HashMap<&'static str, String> {
+	let mut params = HashMap::new();
+	params.insert("page_size", option.per_page().to_string());
+	params.insert("page", option.page().to_string());
+	if let Some(sha) = &option.sha {
+		params.insert("sha", sha.to_string());
+	}
+	if let Some(author) = &option.author {
+		params.insert("author", author.to_string());
+	}
+	if let Some(since) = option.since {
+		params.insert("since", since.to_rfc3339());
+	}
+	if let Some(until) = option.until {
+		params.insert("until", until.to_rfc3339());
+	}
+	params
+}
+
+fn branches_params(option: &BranchListOptions) -> HashMap<&'static str, String> {
+	let mut params = HashMap::new();
+	params.insert("page_size", option.per_page().to_string());
+	params.insert("page", option.page().to_string());
+	params
+}
+
+fn tags_params(option: &TagListOptions) -> HashMap<&'static str, String> {
+	let mut params = HashMap::new();
+	params.insert("page_size", option.per_page().to_string());
+	params.insert("page", option.page().to_string());
+	params
+}
+
+fn releases_params(option: &ReleaseListOptions) -> HashMap<&'static str, String> {
+	let mut params = HashMap::new();
+	params.insert("page_size", option.per_page().to_string());
+	params.insert("page", option.page().to_string());
+	params
+}
+
+/// 创建和更新发布的请求体, 未设置的字段不会发送
+fn release_body(option: &ReleaseUpdateOptions) -> serde_json::Value {
+	let mut body = serde_json::json!({});
+	if let Some(tag_name) = &option.tag_name {
+		body["tag_name"] = tag_name.as_str().into();
+	}
+	if let Some(target_commitish) = &option.target_commitish {
+		body["target_commitish"] = target_commitish.as_str().into();
+	}
+	if let Some(name) = &option.name {
+		body["name"] = name.as_str().into();
+	}
+	if let Some(release_body) = &option.body {
+		body["body"] = release_body.as_str().into();
+	}
+	if let Some(draft) = option.draft {
+		body["draft"] = draft.into();
+	}
+	if let Some(prerelease) = option.prerelease {
+		body["prerelease"] = prerelease.into();
+	}
+	body
+}
+
+fn issues_params(option: &IssueListOptions) -> Result<HashMap<&'static str, String>> {
+	let mut params = HashMap::new();
+	params.insert("page_size", option.per_page().to_string());
+	params.insert("page", option.page().to_string());
+	match option.state {
+		Some(IssueStateFilter::All) => {}
+		Some(state) => {
+			params.insert("state", state.to_string());
+		}
+		None => {
+			params.insert("state", IssueStateFilter::Open.to_string());
+		}
+	}
+	if let Some(labels) = &option.labels {
+		params.insert("labels", labels.join(","));
+	}
+	if let Some(assignee) = &option.assignee {
+		params.insert("assignees", assignee.to_string());
+	}
+	if let Some(creator) = &option.creator {
+		params.insert("authors", creator.to_string());
+	}
+	if let Some(since) = option.since {
+		params.insert("updated_time_begin", since.format("%Y-%m-%d").to_string());
+	}
+	let field = match option.sort {
+		Some(IssueSort::Comments) => return Err(unsupported("sort=comments".to_string())),
+		Some(IssueSort::Updated) => "updated_at",
+		Some(IssueSort::Created) | None => "created_at",
+	};
+	// 平台以 `-` 前缀表示降序
+	let order_by = match option.direction {
+		Some(Direction::Asc) => field.to_string(),
+		Some(Direction::Desc) | None => format!("-{field}"),
+	};
+	params.insert("order_by", order_by);
+	Ok(params)
+}
+
+/// 评论接口中 Issue 和 PR 的路径片段
+fn comment_path(target: CommentTarget) -> &'static str {
+	match target {
+		CommentTarget::Issue => "issues",
+		CommentTarget::PullRequest => "pulls",
+	}
+}
+
+fn comments_params(option: &CommentListOptions) -> HashMap<&'static str, String> {
+	let mut params = HashMap::new();
+	params.insert("page_size", option.per_page().to_string());
+	params.insert("page", option.page().to_string());
+	params
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn sends_token_only_to_platform_hosts() {
+		let client = CnbClient::new();
+		assert!(client.is_platform_url(&format!("{API_URL}/owner/repo")));
+		assert!(
+			client.is_platform_url(&format!("{BASE_URL}/owner/repo/-/releases/download/v1/a.zip"))
+		);
+		assert!(!client.is_platform_url("https://storage.example.com/a.zip?signature=abc"));
+		assert!(!client.is_platform_url("https://cnb.cool.example.com/a.zip"));
+		assert!(!client.is_platform_url("/relative/path"));
 	}
 }
@@ -0,0 +1,248 @@
+//! CNB API 的原始响应模型
+//!
+//! 字段与 CNB OpenAPI 及网页端接口保持一致, 客户端返回的统一类型均由这些模型转换而来
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// 用户信息, 对应 `/user` 和 `/users/{username}`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct User {
+	pub id: String,
+	pub username: String,
+	pub nickname: Option<String>,
+	pub email: Option<String>,
+	pub follower_count: u64,
+	pub follow_count: u64,
+	pub repo_count: Option<u64>,
+}
+
+/// 仓库所有者
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Owner {
+	pub login: String,
+}
+
+/// fork 来源仓库
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ForkSource {
+	pub path: Option<String>,
+}
+
+/// 仓库信息, 对应 `/repos/{owner}/{repo}` 及各仓库列表接口
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Repo {
+	pub id: String,
+	pub owner: Owner,
+	pub name: String,
+	pub full_name: String,
+	pub description: Option<String>,
+	pub visibility_level: Option<String>,
+	pub forked_from_repo: Option<ForkSource>,
+	pub fork_count: Option<u64>,
+	pub language: Option<String>,
+	pub star_count: Option<u64>,
+	/// 默认分支, 列表接口不返回
+	pub default_branch: Option<String>,
+	pub created_at: DateTime<Utc>,
+	pub updated_at: DateTime<Utc>,
+}
+
+/// 默认分支, 对应 `/repos/{owner}/{repo}/-/git/head`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Branch {
+	pub name: String,
+}
+
+/// 组织信息, 对应 `/{group}`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Org {
+	pub id: u64,
+	pub login: String,
+	pub name: Option<String>,
+	pub email: Option<String>,
+	pub description: Option<String>,
+	pub followers: Option<u64>,
+}
+
+/// 提交信息, 对应 `/{owner}/{repo}/-/git/commits/{ref}` 及提交列表接口
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Commit {
+	pub sha: String,
+	pub commit: CommitDetail,
+	/// 提交统计, 仅获取单个提交时返回
+	pub stats: Option<CommitStats>,
+}
+
+/// Git 提交数据
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CommitDetail {
+	pub author: CommitSignature,
+	pub committer: CommitSignature,
+	pub message: String,
+}
+
+/// Git 提交签名
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CommitSignature {
+	pub name: String,
+	pub email: Option<String>,
+	pub date: DateTime<Utc>,
+}
+
+/// 提交统计
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CommitStats {
+	pub total: Option<u64>,
+	pub additions: Option<u64>,
+	pub deletions: Option<u64>,
+}
+
+/// 网页端贡献日历, 键为 `%Y%m%d` 格式的日期
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Calendar(pub BTreeMap<String, CalendarDay>);
+
+/// 单日贡献数据
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CalendarDay {
+	pub score: Option<u64>,
+}
+
+/// 错误响应体, 对应所有非成功响应
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ErrorBody {
+	pub errcode: Option<i64>,
+	pub errmsg: Option<String>,
+}
+
+/// 分支详情, 对应 `/{repo}/-/git/branches` 和 `/{repo}/-/git/branches/{branch}`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BranchDetail {
+	pub name: String,
+	pub commit: BranchCommit,
+	#[serde(alias = "protected")]
+	pub is_protected: Option<bool>,
+}
+
+/// 分支指向的提交
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BranchCommit {
+	pub sha: String,
+}
+
+/// 标签, 对应 `/{repo}/-/git/tags` 和 `/{repo}/-/git/tags/{tag}`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Tag {
+	pub name: String,
+	pub message: Option<String>,
+	/// 标签引用指向的对象SHA
+	pub target: Option<String>,
+	/// 标签引用指向的对象类型, 附注标签为 `tag`
+	pub target_type: Option<String>,
+	/// 标签创建者, 仅附注标签返回
+	pub tagger: Option<Tagger>,
+	/// 标签最终指向的提交
+	pub commit: Option<TagCommit>,
+}
+
+/// 标签指向的提交
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TagCommit {
+	pub sha: String,
+}
+
+/// 附注标签的创建者
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Tagger {
+	pub name: String,
+	pub email: Option<String>,
+	pub date: Option<DateTime<Utc>>,
+}
+
+/// 发布信息, 对应 `/{repo}/-/releases` 及单个发布接口
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Release {
+	pub id: String,
+	pub tag_name: String,
+	pub target_commitish: Option<String>,
+	pub name: Option<String>,
+	pub body: Option<String>,
+	#[serde(default)]
+	pub draft: bool,
+	#[serde(default)]
+	pub prerelease: bool,
+	pub author: Option<ReleaseAuthor>,
+	pub created_at: Option<DateTime<Utc>>,
+	pub published_at: Option<DateTime<Utc>>,
+	#[serde(default)]
+	pub assets: Vec<Asset>,
+}
+
+/// 发布者
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ReleaseAuthor {
+	pub username: String,
+}
+
+/// 发布附件
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Asset {
+	pub id: String,
+	pub name: String,
+	pub size: Option<u64>,
+	pub content_type: Option<String>,
+	/// 平台返回的字段名为 `brower_download_url`
+	#[serde(alias = "brower_download_url")]
+	pub browser_download_url: Option<String>,
+	/// 附件在网页端的路径
+	pub path: Option<String>,
+	pub download_count: Option<u64>,
+}
+
+/// 附件上传地址, 对应 `POST /{repo}/-/releases/{release_id}/asset-upload-url`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AssetUploadUrl {
+	pub upload_url: String,
+	/// 上传完成后用于确认的地址
+	pub verify_url: String,
+}
+
+/// Issue 信息, 对应 `/{repo}/-/issues` 及单个 Issue 接口
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Issue {
+	pub number: String,
+	pub title: String,
+	/// Issue 内容, 仅获取单个 Issue 时返回
+	pub body: Option<String>,
+	pub state: String,
+	#[serde(default)]
+	pub labels: Vec<Label>,
+	#[serde(default)]
+	pub assignees: Vec<IssueUser>,
+	pub author: Option<IssueUser>,
+	pub created_at: DateTime<Utc>,
+	pub updated_at: DateTime<Utc>,
+}
+
+/// 标签
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Label {
+	pub name: String,
+}
+
+/// Issue 及评论中引用的用户
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct IssueUser {
+	pub username: String,
+}
+
+/// 评论, 对应 Issue 和 PR 的评论接口
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Comment {
+	pub id: String,
+	pub body: String,
+	pub author: Option<IssueUser>,
+	pub created_at: DateTime<Utc>,
+	pub updated_at: Option<DateTime<Utc>>,
+}
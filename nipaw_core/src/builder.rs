@@ -0,0 +1,175 @@
+use crate::{
+	Client, Result,
+	cache::CacheStore,
+	config::{BaseUrls, ClientConfig, RetryPolicy, ThrottlePolicy},
+	metrics::MetricsSink,
+};
+use reqwest::header::{HeaderName, HeaderValue};
+use reqwest_middleware::Middleware;
+use std::{sync::Arc, time::Duration};
+
+/// 可由 [ClientConfig] 创建的客户端, 各平台客户端均实现了此 trait
+pub trait FromConfig: Client + Sized {
+	/// 使用指定配置创建客户端
+	///
+	/// # 参数
+	///
+	/// * `config` - 客户端配置, 详见 [ClientConfig]
+	fn from_config(config: ClientConfig) -> Result<Self>;
+}
+
+/// 客户端构建器
+///
+/// 所有配置在 [ClientBuilder::build] 时统一校验, 校验失败返回 [Error](crate::Error)
+///
+/// # 示例
+///
+/// ```ignore
+/// let client: GitHubClient = ClientBuilder::new()
+///     .token("token")
+///     .proxy("http://127.0.0.1:7890")
+///     .timeout(Duration::from_secs(10))
+///     .build()?;
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ClientBuilder {
+	config: ClientConfig,
+	headers: Vec<(String, String)>,
+}
+
+impl ClientBuilder {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// 设置访问令牌
+	pub fn token(mut self, token: impl Into<String>) -> Self {
+		self.config.token = Some(token.into());
+		self
+	}
+
+	/// 设置代理, 支持 `http`, `https`, `socks5` 和 `socks5h` 协议
+	pub fn proxy(mut self, proxy: impl Into<String>) -> Self {
+		self.config.proxy = Some(proxy.into());
+		self
+	}
+
+	/// 设置平台地址配置, 详见 [BaseUrls]
+	pub fn base_urls(mut self, base_urls: BaseUrls) -> Self {
+		self.config.base_urls = base_urls;
+		self
+	}
+
+	/// 设置 API 地址
+	pub fn api_url(mut self, url: impl Into<String>) -> Self {
+		self.config.base_urls.api_url = Some(url.into());
+		self
+	}
+
+	/// 设置网页地址
+	pub fn base_url(mut self, url: impl Into<String>) -> Self {
+		self.config.base_urls.base_url = Some(url.into());
+		self
+	}
+
+	/// 设置网页 API 地址, 仅 GitCode 使用
+	pub fn web_api_url(mut self, url: impl Into<String>) -> Self {
+		self.config.base_urls.web_api_url = Some(url.into());
+		self
+	}
+
+	/// 设置头像地址, 仅 GitHub 使用
+	pub fn avatar_url(mut self, url: impl Into<String>) -> Self {
+		self.config.base_urls.avatar_url = Some(url.into());
+		self
+	}
+
+	/// 设置单个请求的总超时时间
+	pub fn timeout(mut self, timeout: Duration) -> Self {
+		self.config.timeout = Some(timeout);
+		self
+	}
+
+	/// 设置建立连接的超时时间
+	pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+		self.config.connect_timeout = Some(timeout);
+		self
+	}
+
+	/// 设置两次读取响应数据之间的超时时间
+	pub fn read_timeout(mut self, timeout: Duration) -> Self {
+		self.config.read_timeout = Some(timeout);
+		self
+	}
+
+	/// 设置 User-Agent
+	pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+		self.config.user_agent = Some(user_agent.into());
+		self
+	}
+
+	/// 设置重试策略, 详见 [RetryPolicy]
+	pub fn retry(mut self, policy: RetryPolicy) -> Self {
+		self.config.retry = Some(policy);
+		self
+	}
+
+	/// 设置限流策略, 详见 [ThrottlePolicy]
+	pub fn throttle(mut self, policy: ThrottlePolicy) -> Self {
+		self.config.throttle = Some(policy);
+		self
+	}
+
+	/// 设置条件请求缓存, 详见 [cache](crate::cache)
+	pub fn cache(mut self, store: impl CacheStore + 'static) -> Self {
+		self.config.cache = Some(Arc::new(store));
+		self
+	}
+
+	/// 设置请求指标接收器, 详见 [metrics](crate::metrics)
+	pub fn metrics(mut self, sink: impl MetricsSink + 'static) -> Self {
+		self.config.metrics = Some(Arc::new(sink));
+		self
+	}
+
+	/// 注册中间件, 可多次调用, 详见 [ClientConfig::middlewares]
+	pub fn middleware(mut self, middleware: impl Middleware) -> Self {
+		self.config.middlewares.0.push(Arc::new(middleware));
+		self
+	}
+
+	/// 设置是否在返回的统一类型中保留平台原始 JSON, 详见 [ClientConfig::include_raw]
+	pub fn include_raw(mut self, include_raw: bool) -> Self {
+		self.config.include_raw = include_raw;
+		self
+	}
+
+	/// 添加默认请求头, 同名请求头以最后一次设置为准
+	pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+		self.headers.push((name.into(), value.into()));
+		self
+	}
+
+	/// 校验并生成客户端配置
+	pub fn build_config(self) -> Result<ClientConfig> {
+		let mut config = self.config;
+		for (name, value) in self.headers {
+			let name = HeaderName::try_from(name)?;
+			let value = HeaderValue::try_from(value)?;
+			config.headers.insert(name, value);
+		}
+		config.validate()?;
+		Ok(config)
+	}
+
+	/// 校验配置并创建客户端
+	///
+	/// # 示例
+	///
+	/// ```ignore
+	/// let client = ClientBuilder::new().token("token").build::<GiteeClient>()?;
+	/// ```
+	pub fn build<C: FromConfig>(self) -> Result<C> {
+		C::from_config(self.build_config()?)
+	}
+}
@@ -0,0 +1,114 @@
+//! 条件请求缓存
+//!
+//! 开启 [ClientConfig::cache](crate::config::ClientConfig::cache) 后, `GET` 请求的 JSON 响应会按
+//! URL 和访问令牌缓存, 之后的请求携带 `If-None-Match` / `If-Modified-Since` 重新校验,
+//! 平台返回 `304 Not Modified` 时直接使用缓存的响应
+
+use async_trait::async_trait;
+use lru::LruCache;
+use serde::{Deserialize, Serialize};
+use std::{
+	fmt::Debug,
+	num::NonZeroUsize,
+	path::PathBuf,
+	sync::{Arc, Mutex, PoisonError},
+};
+
+/// 缓存的响应
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedResponse {
+	/// 响应的 `ETag`
+	pub etag: Option<String>,
+	/// 响应的 `Last-Modified`
+	pub last_modified: Option<String>,
+	/// 响应头, 分页等信息依赖响应头
+	pub headers: Vec<(String, String)>,
+	/// 响应体
+	#[serde(skip)]
+	pub body: Vec<u8>,
+}
+
+/// 缓存存储, 可自行实现以接入其他存储
+///
+/// `key` 为请求 URL 和访问令牌的摘要, 可直接用作文件名
+#[async_trait]
+pub trait CacheStore: Debug + Send + Sync {
+	/// 读取缓存, 不存在时返回 `None`
+	async fn get(&self, key: &str) -> Option<CachedResponse>;
+
+	/// 写入缓存, 写入失败时应忽略错误, 不影响请求本身
+	async fn put(&self, key: &str, response: CachedResponse);
+}
+
+/// 内存缓存, 超出容量时淘汰最久未使用的响应
+#[derive(Debug, Clone)]
+pub struct MemoryCacheStore {
+	entries: Arc<Mutex<LruCache<String, CachedResponse>>>,
+}
+
+impl MemoryCacheStore {
+	/// 创建内存缓存
+	///
+	/// # 参数
+	///
+	/// * `capacity` - 最多缓存的响应数, 最小为 1
+	pub fn new(capacity: usize) -> Self {
+		let capacity = NonZeroUsize::new(capacity).unwrap_or(NonZeroUsize::MIN);
+		Self { entries: Arc::new(Mutex::new(LruCache::new(capacity))) }
+	}
+}
+
+#[async_trait]
+impl CacheStore for MemoryCacheStore {
+	async fn get(&self, key: &str) -> Option<CachedResponse> {
+		self.entries.lock().unwrap_or_else(PoisonError::into_inner).get(key).cloned()
+	}
+
+	async fn put(&self, key: &str, response: CachedResponse) {
+		self.entries.lock().unwrap_or_else(PoisonError::into_inner).put(key.to_string(), response);
+	}
+}
+
+/// 磁盘缓存, 每个响应保存为目录下的 `{key}.json` 和 `{key}.body` 两个文件
+#[derive(Debug, Clone)]
+pub struct DiskCacheStore {
+	dir: PathBuf,
+}
+
+impl DiskCacheStore {
+	/// 创建磁盘缓存, 目录不存在时在首次写入时创建
+	///
+	/// # 参数
+	///
+	/// * `dir` - 缓存目录
+	pub fn new(dir: impl Into<PathBuf>) -> Self {
+		Self { dir: dir.into() }
+	}
+
+	fn paths(&self, key: &str) -> (PathBuf, PathBuf) {
+		(self.dir.join(format!("{key}.json")), self.dir.join(format!("{key}.body")))
+	}
+}
+
+#[async_trait]
+impl CacheStore for DiskCacheStore {
+	async fn get(&self, key: &str) -> Option<CachedResponse> {
+		let (meta_path, body_path) = self.paths(key);
+		let meta = tokio::fs::read(meta_path).await.ok()?;
+		let mut response: CachedResponse = serde_json::from_slice(&meta).ok()?;
+		response.body = tokio::fs::read(body_path).await.ok()?;
+		Some(response)
+	}
+
+	async fn put(&self, key: &str, response: CachedResponse) {
+		let (meta_path, body_path) = self.paths(key);
+		let Ok(meta) = serde_json::to_vec(&response) else {
+			return;
+		};
+		// 先写响应体, 避免读取到新的元数据和旧的响应体
+		let _ = tokio::fs::create_dir_all(&self.dir).await;
+		if tokio::fs::write(body_path, &response.body).await.is_ok() {
+			let _ = tokio::fs::write(meta_path, meta).await;
+		}
+	}
+}
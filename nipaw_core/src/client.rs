@@ -1,15 +1,31 @@
 use crate::{
-	Result,
-	option::{CommitListOptions, OrgRepoListOptions, ReposListOptions},
+	Error, Result,
+	middleware::CALL_DEADLINE,
+	option::{
+		BranchListOptions, CommentListOptions, CommitListOptions, IssueListOptions, IssueOptions,
+		IssueUpdateOptions, OrgRepoListOptions, ReleaseListOptions, ReleaseOptions,
+		ReleaseUpdateOptions, ReposListOptions, TagListOptions,
+	},
 	types::{
+		branch::BranchInfo,
 		collaborator::{CollaboratorPermission, CollaboratorResult},
+		comment::{CommentInfo, CommentTarget},
 		commit::CommitInfo,
+		issue::IssueInfo,
 		org::OrgInfo,
+		rate_limit::RateLimitInfo,
+		release::{AssetStream, AssetUpload, ReleaseAsset, ReleaseInfo},
 		repo::RepoInfo,
+		tag::TagInfo,
 		user::{ContributionResult, UserInfo},
 	},
 };
 use async_trait::async_trait;
+use futures::stream::BoxStream;
+use std::{
+	future::Future,
+	time::{Duration, Instant},
+};
 
 #[async_trait]
 pub trait Client: Send + Sync {
@@ -26,11 +42,15 @@ pub trait Client: Send + Sync {
 	/// ```
 	fn set_token(&mut self, token: &str) -> Result<()>;
 
-	/// 设置代理
+	/// 设置代理, 可重复调用, 新的代理会替换之前的设置
 	///
 	/// # 参数
 	///
-	/// * `proxy` - 代理字符串
+	/// * `proxy` - 代理字符串, 支持 `http`, `https`, `socks5` 和 `socks5h` 协议
+	///
+	/// # 错误
+	///
+	/// 代理地址格式不正确时返回 [Error::ProxyInvalid](crate::Error::ProxyInvalid)
 	///
 	/// # 示例
 	///
@@ -78,6 +98,20 @@ pub trait Client: Send + Sync {
 		options: Option<OrgRepoListOptions>,
 	) -> Result<Vec<RepoInfo>>;
 
+	/// 获取组织全部仓库信息, 自动按平台的分页方式翻页
+	///
+	/// # 参数
+	///
+	/// * `org_name` - 组织名
+	/// * `options` - 获取仓库列表选项, 详见 [OrgRepoListOptions], 其中 `page` 为起始页码
+	/// * `limit` - 最多获取的仓库数量, 默认获取全部
+	fn get_org_repos_stream<'a>(
+		&'a self,
+		org_name: &'a str,
+		options: Option<OrgRepoListOptions>,
+		limit: Option<usize>,
+	) -> BoxStream<'a, Result<RepoInfo>>;
+
 	/// 获取组织头像URL
 	///
 	/// # 参数
@@ -101,6 +135,18 @@ pub trait Client: Send + Sync {
 	///
 	async fn get_user_repos(&self, option: Option<ReposListOptions>) -> Result<Vec<RepoInfo>>;
 
+	/// 获取用户全部仓库信息, 自动按平台的分页方式翻页
+	///
+	/// # 参数
+	///
+	/// * `option` - 获取仓库列表选项, 详见 [ReposListOptions], 其中 `page` 为起始页码
+	/// * `limit` - 最多获取的仓库数量, 默认获取全部
+	fn get_user_repos_stream(
+		&self,
+		option: Option<ReposListOptions>,
+		limit: Option<usize>,
+	) -> BoxStream<'_, Result<RepoInfo>>;
+
 	/// 根据用户名获取用户仓库信息列表
 	///
 	/// # 参数
@@ -113,6 +159,20 @@ pub trait Client: Send + Sync {
 		option: Option<ReposListOptions>,
 	) -> Result<Vec<RepoInfo>>;
 
+	/// 根据用户名获取用户全部仓库信息, 自动按平台的分页方式翻页
+	///
+	/// # 参数
+	///
+	/// * `user_name` - 用户名
+	/// * `option` - 获取仓库列表选项, 详见 [ReposListOptions], 其中 `page` 为起始页码
+	/// * `limit` - 最多获取的仓库数量, 默认获取全部
+	fn get_user_repos_with_name_stream<'a>(
+		&'a self,
+		user_name: &'a str,
+		option: Option<ReposListOptions>,
+		limit: Option<usize>,
+	) -> BoxStream<'a, Result<RepoInfo>>;
+
 	/// 获取仓库提交信息
 	///
 	/// # 参数
@@ -137,6 +197,20 @@ pub trait Client: Send + Sync {
 		option: Option<CommitListOptions>,
 	) -> Result<Vec<CommitInfo>>;
 
+	/// 获取仓库全部提交信息, 自动按平台的分页方式翻页
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径，格式为 `(owner, repo)`
+	/// * `option` - 获取提交列表选项, 详见 [CommitListOptions], 其中 `page` 为起始页码
+	/// * `limit` - 最多获取的提交数量, 默认获取全部
+	fn get_commit_infos_stream<'a>(
+		&'a self,
+		repo_path: (&'a str, &'a str),
+		option: Option<CommitListOptions>,
+		limit: Option<usize>,
+	) -> BoxStream<'a, Result<CommitInfo>>;
+
 	/// 添加仓库协作者， 如果仓库属于某个组织下的则为外部协作者
 	///
 	/// # 参数
@@ -151,4 +225,503 @@ pub trait Client: Send + Sync {
 		user_name: &str,
 		permission: Option<CollaboratorPermission>,
 	) -> Result<CollaboratorResult>;
+
+	/// 获取仓库分支列表
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径，格式为 `(owner, repo)`
+	/// * `option` - 获取分支列表选项, 详见 [BranchListOptions]
+	async fn get_branches(
+		&self,
+		repo_path: (&str, &str),
+		option: Option<BranchListOptions>,
+	) -> Result<Vec<BranchInfo>>;
+
+	/// 获取仓库全部分支, 自动按平台的分页方式翻页
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径，格式为 `(owner, repo)`
+	/// * `option` - 获取分支列表选项, 详见 [BranchListOptions], 其中 `page` 为起始页码
+	/// * `limit` - 最多获取的分支数量, 默认获取全部
+	fn get_branches_stream<'a>(
+		&'a self,
+		repo_path: (&'a str, &'a str),
+		option: Option<BranchListOptions>,
+		limit: Option<usize>,
+	) -> BoxStream<'a, Result<BranchInfo>>;
+
+	/// 获取分支信息
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径，格式为 `(owner, repo)`
+	/// * `branch` - 分支名称
+	async fn get_branch(&self, repo_path: (&str, &str), branch: &str) -> Result<BranchInfo>;
+
+	/// 创建分支
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径，格式为 `(owner, repo)`
+	/// * `branch` - 新分支名称
+	/// * `from` - 起点, 可以是分支名、标签名或提交SHA
+	async fn create_branch(
+		&self,
+		repo_path: (&str, &str),
+		branch: &str,
+		from: &str,
+	) -> Result<BranchInfo>;
+
+	/// 删除分支
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径，格式为 `(owner, repo)`
+	/// * `branch` - 分支名称
+	///
+	/// # 错误
+	///
+	/// Gitee 不支持删除分支, 返回 [Error::Unsupported](crate::Error::Unsupported)
+	async fn delete_branch(&self, repo_path: (&str, &str), branch: &str) -> Result<()>;
+
+	/// 获取仓库标签列表
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径，格式为 `(owner, repo)`
+	/// * `option` - 获取标签列表选项, 详见 [TagListOptions]
+	///
+	/// GitHub 的标签列表接口不返回附注信息, 其结果中 `tagger` 和 `message` 为空且 `annotated` 为 `false`,
+	/// 需要完整信息时使用 [get_tag](Client::get_tag)
+	async fn get_tags(
+		&self,
+		repo_path: (&str, &str),
+		option: Option<TagListOptions>,
+	) -> Result<Vec<TagInfo>>;
+
+	/// 获取仓库全部标签, 自动按平台的分页方式翻页
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径，格式为 `(owner, repo)`
+	/// * `option` - 获取标签列表选项, 详见 [TagListOptions], 其中 `page` 为起始页码
+	/// * `limit` - 最多获取的标签数量, 默认获取全部
+	fn get_tags_stream<'a>(
+		&'a self,
+		repo_path: (&'a str, &'a str),
+		option: Option<TagListOptions>,
+		limit: Option<usize>,
+	) -> BoxStream<'a, Result<TagInfo>>;
+
+	/// 获取标签信息
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径，格式为 `(owner, repo)`
+	/// * `tag` - 标签名称
+	///
+	/// Gitee 和 GitCode 没有获取单个标签的接口, 通过遍历标签列表查找
+	async fn get_tag(&self, repo_path: (&str, &str), tag: &str) -> Result<TagInfo>;
+
+	/// 创建标签
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径，格式为 `(owner, repo)`
+	/// * `tag` - 标签名称
+	/// * `target` - 标签指向的分支名或提交SHA
+	/// * `message` - 标签说明, 设置时创建附注标签, 否则创建轻量标签
+	async fn create_tag(
+		&self,
+		repo_path: (&str, &str),
+		tag: &str,
+		target: &str,
+		message: Option<&str>,
+	) -> Result<TagInfo>;
+
+	/// 删除标签
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径，格式为 `(owner, repo)`
+	/// * `tag` - 标签名称
+	///
+	/// # 错误
+	///
+	/// Gitee 不支持删除标签, 返回 [Error::Unsupported](crate::Error::Unsupported)
+	async fn delete_tag(&self, repo_path: (&str, &str), tag: &str) -> Result<()>;
+
+	/// 获取仓库发布列表
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径，格式为 `(owner, repo)`
+	/// * `option` - 获取发布列表选项, 详见 [ReleaseListOptions]
+	async fn get_releases(
+		&self,
+		repo_path: (&str, &str),
+		option: Option<ReleaseListOptions>,
+	) -> Result<Vec<ReleaseInfo>>;
+
+	/// 获取仓库全部发布, 自动按平台的分页方式翻页
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径，格式为 `(owner, repo)`
+	/// * `option` - 获取发布列表选项, 详见 [ReleaseListOptions], 其中 `page` 为起始页码
+	/// * `limit` - 最多获取的发布数量, 默认获取全部
+	fn get_releases_stream<'a>(
+		&'a self,
+		repo_path: (&'a str, &'a str),
+		option: Option<ReleaseListOptions>,
+		limit: Option<usize>,
+	) -> BoxStream<'a, Result<ReleaseInfo>>;
+
+	/// 获取最新发布
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径，格式为 `(owner, repo)`
+	async fn get_latest_release(&self, repo_path: (&str, &str)) -> Result<ReleaseInfo>;
+
+	/// 获取指定标签的发布
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径，格式为 `(owner, repo)`
+	/// * `tag` - 标签名称
+	async fn get_release_by_tag(&self, repo_path: (&str, &str), tag: &str) -> Result<ReleaseInfo>;
+
+	/// 创建发布
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径，格式为 `(owner, repo)`
+	/// * `option` - 发布选项, 详见 [ReleaseOptions]
+	async fn create_release(
+		&self,
+		repo_path: (&str, &str),
+		option: ReleaseOptions,
+	) -> Result<ReleaseInfo>;
+
+	/// 更新发布
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径，格式为 `(owner, repo)`
+	/// * `release_id` - 发布ID, 详见 [ReleaseInfo::id]
+	/// * `option` - 发布选项, 详见 [ReleaseUpdateOptions], 未设置的字段保持不变
+	async fn update_release(
+		&self,
+		repo_path: (&str, &str),
+		release_id: &str,
+		option: ReleaseUpdateOptions,
+	) -> Result<ReleaseInfo>;
+
+	/// 删除发布
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径，格式为 `(owner, repo)`
+	/// * `release_id` - 发布ID, 详见 [ReleaseInfo::id]
+	///
+	/// # 错误
+	///
+	/// GitCode 不支持删除发布, 返回 [Error::Unsupported](crate::Error::Unsupported)
+	async fn delete_release(&self, repo_path: (&str, &str), release_id: &str) -> Result<()>;
+
+	/// 上传发布附件
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径，格式为 `(owner, repo)`
+	/// * `release_id` - 发布ID, 详见 [ReleaseInfo::id]
+	/// * `asset` - 待上传的附件, 详见 [AssetUpload]
+	async fn upload_release_asset(
+		&self,
+		repo_path: (&str, &str),
+		release_id: &str,
+		asset: AssetUpload,
+	) -> Result<ReleaseAsset>;
+
+	/// 下载发布附件, 返回附件内容的字节流
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径，格式为 `(owner, repo)`
+	/// * `release_id` - 发布ID, 详见 [ReleaseInfo::id]
+	/// * `asset_id` - 附件ID, 详见 [ReleaseAsset::id]
+	async fn download_release_asset(
+		&self,
+		repo_path: (&str, &str),
+		release_id: &str,
+		asset_id: &str,
+	) -> Result<AssetStream>;
+
+	/// 删除发布附件
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径，格式为 `(owner, repo)`
+	/// * `release_id` - 发布ID, 详见 [ReleaseInfo::id]
+	/// * `asset_id` - 附件ID, 详见 [ReleaseAsset::id]
+	///
+	/// # 错误
+	///
+	/// GitCode 不支持删除附件, 返回 [Error::Unsupported](crate::Error::Unsupported)
+	async fn delete_release_asset(
+		&self,
+		repo_path: (&str, &str),
+		release_id: &str,
+		asset_id: &str,
+	) -> Result<()>;
+
+	/// 获取仓库 Issue 列表, 不包含 PR
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径，格式为 `(owner, repo)`
+	/// * `option` - 获取 Issue 列表选项, 详见 [IssueListOptions]
+	async fn get_issues(
+		&self,
+		repo_path: (&str, &str),
+		option: Option<IssueListOptions>,
+	) -> Result<Vec<IssueInfo>>;
+
+	/// 获取仓库全部 Issue, 不包含 PR, 自动按平台的分页方式翻页
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径，格式为 `(owner, repo)`
+	/// * `option` - 获取 Issue 列表选项, 详见 [IssueListOptions], 其中 `page` 为起始页码
+	/// * `limit` - 最多获取的 Issue 数量, 默认获取全部
+	fn get_issues_stream<'a>(
+		&'a self,
+		repo_path: (&'a str, &'a str),
+		option: Option<IssueListOptions>,
+		limit: Option<usize>,
+	) -> BoxStream<'a, Result<IssueInfo>>;
+
+	/// 获取 Issue 信息
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径，格式为 `(owner, repo)`
+	/// * `number` - Issue 编号
+	async fn get_issue(&self, repo_path: (&str, &str), number: &str) -> Result<IssueInfo>;
+
+	/// 创建 Issue
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径，格式为 `(owner, repo)`
+	/// * `option` - Issue 选项, 详见 [IssueOptions]
+	async fn create_issue(
+		&self,
+		repo_path: (&str, &str),
+		option: IssueOptions,
+	) -> Result<IssueInfo>;
+
+	/// 更新 Issue
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径，格式为 `(owner, repo)`
+	/// * `number` - Issue 编号
+	/// * `option` - Issue 选项, 详见 [IssueUpdateOptions], 未设置的字段保持不变
+	async fn update_issue(
+		&self,
+		repo_path: (&str, &str),
+		number: &str,
+		option: IssueUpdateOptions,
+	) -> Result<IssueInfo>;
+
+	/// 关闭 Issue
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径，格式为 `(owner, repo)`
+	/// * `number` - Issue 编号
+	async fn close_issue(&self, repo_path: (&str, &str), number: &str) -> Result<IssueInfo>;
+
+	/// 锁定 Issue, 锁定后只有协作者可以评论
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径，格式为 `(owner, repo)`
+	/// * `number` - Issue 编号
+	///
+	/// # 错误
+	///
+	/// 仅 GitHub 支持, 其他平台返回 [Error::Unsupported](crate::Error::Unsupported)
+	async fn lock_issue(&self, repo_path: (&str, &str), number: &str) -> Result<()>;
+
+	/// 解锁 Issue
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径，格式为 `(owner, repo)`
+	/// * `number` - Issue 编号
+	///
+	/// # 错误
+	///
+	/// 仅 GitHub 支持, 其他平台返回 [Error::Unsupported](crate::Error::Unsupported)
+	async fn unlock_issue(&self, repo_path: (&str, &str), number: &str) -> Result<()>;
+
+	/// 获取 Issue 或 PR 的评论列表
+	///
+	/// PR 只返回普通评论, 代码评论使用 [get_review_comments](Client::get_review_comments) 获取
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径，格式为 `(owner, repo)`
+	/// * `target` - 评论所属的对象, 详见 [CommentTarget]
+	/// * `number` - Issue 或 PR 编号
+	/// * `option` - 获取评论列表选项, 详见 [CommentListOptions]
+	async fn get_comments(
+		&self,
+		repo_path: (&str, &str),
+		target: CommentTarget,
+		number: &str,
+		option: Option<CommentListOptions>,
+	) -> Result<Vec<CommentInfo>>;
+
+	/// 获取 Issue 或 PR 的全部评论, 自动按平台的分页方式翻页
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径，格式为 `(owner, repo)`
+	/// * `target` - 评论所属的对象, 详见 [CommentTarget]
+	/// * `number` - Issue 或 PR 编号
+	/// * `option` - 获取评论列表选项, 详见 [CommentListOptions], 其中 `page` 为起始页码
+	/// * `limit` - 最多获取的评论数量, 默认获取全部
+	fn get_comments_stream<'a>(
+		&'a self,
+		repo_path: (&'a str, &'a str),
+		target: CommentTarget,
+		number: &'a str,
+		option: Option<CommentListOptions>,
+		limit: Option<usize>,
+	) -> BoxStream<'a, Result<CommentInfo>>;
+
+	/// 获取 PR 的代码评论列表
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径，格式为 `(owner, repo)`
+	/// * `number` - PR 编号
+	/// * `option` - 获取评论列表选项, 详见 [CommentListOptions]
+	///
+	/// # 错误
+	///
+	/// CNB 返回 [Error::Unsupported](crate::Error::Unsupported)
+	async fn get_review_comments(
+		&self,
+		repo_path: (&str, &str),
+		number: &str,
+		option: Option<CommentListOptions>,
+	) -> Result<Vec<CommentInfo>>;
+
+	/// 获取 PR 的全部代码评论, 自动按平台的分页方式翻页
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径，格式为 `(owner, repo)`
+	/// * `number` - PR 编号
+	/// * `option` - 获取评论列表选项, 详见 [CommentListOptions], 其中 `page` 为起始页码
+	/// * `limit` - 最多获取的评论数量, 默认获取全部
+	///
+	/// # 错误
+	///
+	/// CNB 返回 [Error::Unsupported](crate::Error::Unsupported)
+	fn get_review_comments_stream<'a>(
+		&'a self,
+		repo_path: (&'a str, &'a str),
+		number: &'a str,
+		option: Option<CommentListOptions>,
+		limit: Option<usize>,
+	) -> BoxStream<'a, Result<CommentInfo>>;
+
+	/// 发表评论
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径，格式为 `(owner, repo)`
+	/// * `target` - 评论所属的对象, 详见 [CommentTarget]
+	/// * `number` - Issue 或 PR 编号
+	/// * `body` - 评论内容
+	async fn create_comment(
+		&self,
+		repo_path: (&str, &str),
+		target: CommentTarget,
+		number: &str,
+		body: &str,
+	) -> Result<CommentInfo>;
+
+	/// 编辑评论
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径，格式为 `(owner, repo)`
+	/// * `target` - 评论所属的对象, 详见 [CommentTarget]
+	/// * `number` - Issue 或 PR 编号, 仅 CNB 使用
+	/// * `comment_id` - 评论ID
+	/// * `body` - 新的评论内容
+	async fn update_comment(
+		&self,
+		repo_path: (&str, &str),
+		target: CommentTarget,
+		number: &str,
+		comment_id: &str,
+		body: &str,
+	) -> Result<CommentInfo>;
+
+	/// 删除评论
+	///
+	/// # 参数
+	///
+	/// * `repo_path` - 仓库路径，格式为 `(owner, repo)`
+	/// * `target` - 评论所属的对象, 详见 [CommentTarget]
+	/// * `number` - Issue 或 PR 编号, 仅 CNB 使用
+	/// * `comment_id` - 评论ID
+	///
+	/// # 错误
+	///
+	/// CNB 返回 [Error::Unsupported](crate::Error::Unsupported)
+	async fn delete_comment(
+		&self,
+		repo_path: (&str, &str),
+		target: CommentTarget,
+		number: &str,
+		comment_id: &str,
+	) -> Result<()>;
+
+	/// 获取当前的限流状态
+	///
+	/// 平台提供限流查询接口时使用该接口, 否则从一次轻量请求的响应头中读取, 该请求本身也计入限流
+	async fn get_rate_limit(&self) -> Result<RateLimitInfo>;
+}
+
+/// 为单次调用设置超时, 超时后放弃调用并返回 [Error::Timeout]
+///
+/// 截止时间会传递给调用中的每个 HTTP 请求, 请求的超时不超过剩余时间, 重试也不会超出截止时间;
+/// 嵌套调用时使用较早的截止时间。列表接口也可以通过选项中的 `timeout` 为每个请求单独设置超时
+///
+/// # 示例
+///
+/// ```ignore
+/// let repo = with_timeout(Duration::from_secs(5), client.get_repo_info(("owner", "repo"))).await?;
+/// ```
+pub async fn with_timeout<T>(
+	timeout: Duration,
+	call: impl Future<Output = Result<T>>,
+) -> Result<T> {
+	let deadline = Instant::now() + timeout;
+	let deadline = CALL_DEADLINE.try_with(|outer| (*outer).min(deadline)).unwrap_or(deadline);
+	CALL_DEADLINE
+		.scope(deadline, tokio::time::timeout(timeout, call))
+		.await
+		.map_err(|_| Error::Timeout)?
 }
@@ -0,0 +1,232 @@
+use crate::{
+	Error, Result,
+	cache::CacheStore,
+	metrics::MetricsSink,
+	middleware::{CacheMiddleware, RetryMiddleware, ThrottleMiddleware, TimeoutMiddleware},
+};
+use reqwest::{
+	Method, Proxy,
+	header::{HeaderMap, USER_AGENT},
+};
+use reqwest_middleware::{ClientBuilder, Middleware};
+use std::{fmt, sync::Arc, time::Duration};
+use url::Url;
+
+const DEFAULT_USER_AGENT: &str = "nipaw";
+const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+const DEFAULT_READ_TIMEOUT: Duration = Duration::from_secs(30);
+const PROXY_SCHEMES: [&str; 4] = ["http", "https", "socks5", "socks5h"];
+
+/// 平台地址配置
+///
+/// 未设置的地址使用各平台的默认值, 用于对接私有化部署的实例
+#[derive(Debug, Clone, Default)]
+pub struct BaseUrls {
+	/// API 地址, 如 `https://api.github.com`
+	pub api_url: Option<String>,
+	/// 网页地址, 如 `https://github.com`
+	pub base_url: Option<String>,
+	/// 网页 API 地址, 仅 GitCode 使用
+	pub web_api_url: Option<String>,
+	/// 头像地址, 仅 GitHub 使用
+	pub avatar_url: Option<String>,
+}
+
+impl BaseUrls {
+	/// 获取 API 地址, 未设置时使用 `default`
+	pub fn api_url_or(&self, default: &str) -> Result<String> {
+		resolve_url(self.api_url.as_deref(), default)
+	}
+
+	/// 获取网页地址, 未设置时使用 `default`
+	pub fn base_url_or(&self, default: &str) -> Result<String> {
+		resolve_url(self.base_url.as_deref(), default)
+	}
+
+	/// 获取网页 API 地址, 未设置时使用 `default`
+	pub fn web_api_url_or(&self, default: &str) -> Result<String> {
+		resolve_url(self.web_api_url.as_deref(), default)
+	}
+
+	/// 获取头像地址, 未设置时使用 `default`
+	pub fn avatar_url_or(&self, default: &str) -> Result<String> {
+		resolve_url(self.avatar_url.as_deref(), default)
+	}
+}
+
+fn resolve_url(url: Option<&str>, default: &str) -> Result<String> {
+	let url = url.unwrap_or(default);
+	Url::parse(url)?;
+	Ok(url.trim_end_matches('/').to_string())
+}
+
+/// 重试策略
+///
+/// 重试间隔从 `base_delay` 开始按指数增长, 不超过 `max_delay`;
+/// 平台通过 `Retry-After` 或限流重置时间给出等待时间时, 优先使用平台给出的时间
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+	/// 最大重试次数, 为 0 时不重试
+	pub max_retries: u32,
+	/// 首次重试的间隔
+	pub base_delay: Duration,
+	/// 单次重试的最长间隔, 平台要求等待的时间超过该值时不再重试
+	pub max_delay: Duration,
+	/// 是否为重试间隔加入随机抖动, 避免多个请求同时重试
+	pub jitter: bool,
+	/// 允许重试的请求方法, 默认只重试 `GET`, `HEAD` 和 `OPTIONS`, 避免写操作被重复提交
+	pub methods: Vec<Method>,
+}
+
+impl Default for RetryPolicy {
+	fn default() -> Self {
+		Self {
+			max_retries: 3,
+			base_delay: Duration::from_millis(500),
+			max_delay: Duration::from_secs(30),
+			jitter: true,
+			methods: vec![Method::GET, Method::HEAD, Method::OPTIONS],
+		}
+	}
+}
+
+/// 限流策略, 在客户端主动控制请求速率和并发数
+///
+/// 每个客户端实例持有独立的令牌桶和并发限制
+#[derive(Debug, Clone, Default)]
+pub struct ThrottlePolicy {
+	/// 每秒补充的令牌数, 即长期平均的每秒请求数, 未设置时不限制速率
+	pub requests_per_second: Option<f64>,
+	/// 令牌桶容量, 即允许的突发请求数, 最小为 1
+	pub burst: u32,
+	/// 同时进行的最大请求数, 未设置时不限制
+	pub max_in_flight: Option<usize>,
+}
+
+/// 用户注册的中间件, 详见 [ClientConfig::middlewares]
+#[derive(Clone, Default)]
+pub struct Middlewares(pub Vec<Arc<dyn Middleware>>);
+
+impl fmt::Debug for Middlewares {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "Middlewares({})", self.0.len())
+	}
+}
+
+/// 客户端配置
+///
+/// 每个客户端实例持有独立的 HTTP 客户端, 修改配置不会影响其他实例
+#[derive(Debug, Clone, Default)]
+pub struct ClientConfig {
+	/// 访问令牌
+	pub token: Option<String>,
+	/// 平台地址配置, 详见 [BaseUrls]
+	pub base_urls: BaseUrls,
+	/// 代理地址, 支持 `http`, `https`, `socks5` 和 `socks5h` 协议
+	pub proxy: Option<String>,
+	/// 单个请求的总超时时间, 包含连接和读取响应体, 默认不限制
+	pub timeout: Option<Duration>,
+	/// 建立连接的超时时间, 默认 10 秒
+	pub connect_timeout: Option<Duration>,
+	/// 两次读取响应数据之间的超时时间, 默认 30 秒
+	pub read_timeout: Option<Duration>,
+	/// 请求使用的 User-Agent, 默认为 `nipaw`
+	pub user_agent: Option<String>,
+	/// 额外的默认请求头, 会覆盖平台的同名默认请求头
+	pub headers: HeaderMap,
+	/// 重试策略, 未设置时不重试, 详见 [RetryPolicy]
+	pub retry: Option<RetryPolicy>,
+	/// 限流策略, 未设置时不限流, 详见 [ThrottlePolicy]
+	pub throttle: Option<ThrottlePolicy>,
+	/// 条件请求缓存, 未设置时不缓存, 详见 [cache](crate::cache)
+	pub cache: Option<Arc<dyn CacheStore>>,
+	/// 请求指标接收器, 未设置时不记录, 详见 [metrics](crate::metrics)
+	pub metrics: Option<Arc<dyn MetricsSink>>,
+	/// 用户注册的中间件, 按注册顺序位于平台中间件之内
+	///
+	/// 可用于审计日志、网关请求头或请求签名, 中间件会收到每次重试的请求和平台返回的原始响应
+	pub middlewares: Middlewares,
+	/// 是否在返回的统一类型中保留平台原始 JSON, 默认关闭
+	///
+	/// 开启后 [RepoInfo](crate::types::repo::RepoInfo), [UserInfo](crate::types::user::UserInfo),
+	/// [OrgInfo](crate::types::org::OrgInfo) 和 [CommitInfo](crate::types::commit::CommitInfo)
+	/// 的 `raw` 字段会填充平台返回的完整数据
+	pub include_raw: bool,
+}
+
+impl ClientConfig {
+	/// 校验配置, 各平台客户端创建时会调用此方法
+	pub fn validate(&self) -> Result<()> {
+		if self.token.as_deref().is_some_and(str::is_empty) {
+			return Err(Error::TokenEmpty);
+		}
+		if let Some(proxy) = &self.proxy {
+			validate_proxy(proxy)?;
+		}
+		Ok(())
+	}
+
+	/// 根据配置创建 HTTP 客户端构建器
+	///
+	/// # 参数
+	///
+	/// * `headers` - 平台的默认请求头
+	pub fn http_client_builder(&self, mut headers: HeaderMap) -> Result<reqwest::ClientBuilder> {
+		let user_agent = self.user_agent.as_deref().unwrap_or(DEFAULT_USER_AGENT);
+		headers.insert(USER_AGENT, user_agent.parse()?);
+		headers.extend(self.headers.clone());
+		let mut builder = reqwest::Client::builder().default_headers(headers);
+		if let Some(proxy) = &self.proxy {
+			builder = builder.proxy(Proxy::all(proxy)?);
+		}
+		if let Some(timeout) = self.timeout {
+			builder = builder.timeout(timeout);
+		}
+		builder = builder
+			.connect_timeout(self.connect_timeout.unwrap_or(DEFAULT_CONNECT_TIMEOUT))
+			.read_timeout(self.read_timeout.unwrap_or(DEFAULT_READ_TIMEOUT));
+		Ok(builder)
+	}
+
+	/// 根据配置创建中间件构建器, 平台自身的中间件应在此之后添加
+	///
+	/// # 参数
+	///
+	/// * `client` - 由 [ClientConfig::http_client_builder] 创建的 HTTP 客户端
+	pub fn middleware_builder(&self, client: reqwest::Client) -> ClientBuilder {
+		let mut builder = ClientBuilder::new(client);
+		// 缓存位于最外层, 命中 304 时不再经过重试和限流
+		if let Some(cache) = &self.cache {
+			builder = builder.with(CacheMiddleware::new(cache.clone()));
+		}
+		if let Some(retry) = &self.retry {
+			builder = builder.with(RetryMiddleware::new(retry.clone()));
+		}
+		// 限流在重试之后添加, 每次重试同样受限流控制
+		if let Some(throttle) = &self.throttle {
+			builder = builder.with(ThrottleMiddleware::new(throttle.clone()));
+		}
+		builder.with(TimeoutMiddleware)
+	}
+
+	/// 添加用户注册的中间件, 平台自身的中间件添加完成后调用
+	///
+	/// # 参数
+	///
+	/// * `builder` - 已添加平台中间件的构建器
+	pub fn with_user_middlewares(&self, mut builder: ClientBuilder) -> ClientBuilder {
+		for middleware in &self.middlewares.0 {
+			builder = builder.with_arc(middleware.clone());
+		}
+		builder
+	}
+}
+
+/// 校验代理地址, 只接受带主机名的 `http`, `https`, `socks5` 和 `socks5h` 地址
+pub fn validate_proxy(proxy: &str) -> Result<()> {
+	let url = Url::parse(proxy).map_err(|_| Error::ProxyInvalid(proxy.to_string()))?;
+	if !PROXY_SCHEMES.contains(&url.scheme()) || url.host_str().is_none() {
+		return Err(Error::ProxyInvalid(proxy.to_string()));
+	}
+	Ok(())
+}
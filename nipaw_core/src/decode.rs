@@ -0,0 +1,100 @@
+use crate::{
+	Error, Result,
+	types::{WithRaw, release::AssetStream},
+};
+use futures::StreamExt;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use thiserror::Error;
+
+/// 字段解码失败的原因
+#[derive(Debug, Error)]
+pub enum DecodeError {
+	#[error(transparent)]
+	Json(#[from] serde_json::Error),
+	#[error("invalid value: {0}")]
+	InvalidValue(String),
+}
+
+/// 从响应体解码平台的原始模型, 失败时返回带字段路径的 [Error::Decode]
+pub fn from_slice<T: DeserializeOwned>(bytes: &[u8]) -> Result<T> {
+	let deserializer = &mut serde_json::Deserializer::from_slice(bytes);
+	serde_path_to_error::deserialize(deserializer).map_err(|e| Error::Decode {
+		entity: entity_name::<T>(),
+		field: e.path().to_string(),
+		source: e.into_inner().into(),
+	})
+}
+
+/// 将 JSON 值解码为平台的原始模型, 失败时返回带字段路径的 [Error::Decode]
+pub fn from_value<T: DeserializeOwned>(value: Value) -> Result<T> {
+	serde_path_to_error::deserialize(value).map_err(|e| Error::Decode {
+		entity: entity_name::<T>(),
+		field: e.path().to_string(),
+		source: e.into_inner().into(),
+	})
+}
+
+/// 读取响应体并解码为平台的原始模型, 详见 [from_slice]
+pub async fn json<T: DeserializeOwned>(resp: reqwest::Response) -> Result<T> {
+	let bytes = resp.bytes().await?;
+	from_slice(&bytes)
+}
+
+/// 读取响应体并解码为平台的原始模型, `include_raw` 为 `true` 时同时返回原始 JSON
+pub async fn json_with_raw<M: DeserializeOwned>(
+	resp: reqwest::Response,
+	include_raw: bool,
+) -> Result<(M, Option<Value>)> {
+	if !include_raw {
+		return Ok((json(resp).await?, None));
+	}
+	let raw: Value = json(resp).await?;
+	Ok((from_value(raw.clone())?, Some(raw)))
+}
+
+/// 读取响应体, 经原始模型 `M` 转换为统一类型
+///
+/// `include_raw` 为 `true` 时在统一类型中保留平台原始 JSON
+pub async fn json_into<M, T>(resp: reqwest::Response, include_raw: bool) -> Result<T>
+where
+	M: DeserializeOwned,
+	T: From<M> + WithRaw,
+{
+	let (model, raw) = json_with_raw::<M>(resp, include_raw).await?;
+	let mut item = T::from(model);
+	if let Some(raw) = raw {
+		item.set_raw(raw);
+	}
+	Ok(item)
+}
+
+/// 读取列表响应体, 详见 [json_into]
+pub async fn json_list_into<M, T>(resp: reqwest::Response, include_raw: bool) -> Result<Vec<T>>
+where
+	M: DeserializeOwned,
+	T: From<M> + WithRaw,
+{
+	if !include_raw {
+		return Ok(json::<Vec<M>>(resp).await?.into_iter().map(T::from).collect());
+	}
+	let raws: Vec<Value> = json(resp).await?;
+	raws.into_iter()
+		.map(|raw| {
+			let mut item = T::from(from_value::<M>(raw.clone())?);
+			item.set_raw(raw);
+			Ok(item)
+		})
+		.collect()
+}
+
+/// 将响应体转换为字节流, 用于附件下载等不适合整体读入内存的响应
+pub fn bytes_stream(resp: reqwest::Response) -> AssetStream {
+	resp.bytes_stream().map(|chunk| chunk.map_err(Error::from)).boxed()
+}
+
+/// 类型名称, 去除模块路径和泛型包装, 如 `Vec<model::Repo>` 为 `Repo`
+pub fn entity_name<T>() -> &'static str {
+	let name = std::any::type_name::<T>().trim_end_matches('>');
+	name.rsplit(['<', ':']).next().unwrap_or(name)
+}
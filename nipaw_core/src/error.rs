@@ -1,19 +1,169 @@
+use crate::decode::DecodeError;
+use crate::types::rate_limit::RateLimitInfo;
+use reqwest::StatusCode;
 use thiserror::Error;
+use url::Url;
+
+/// 查询参数中需要隐藏的访问令牌
+const REDACTED_PARAMS: [&str; 3] = ["access_token", "private_token", "token"];
 
 #[derive(Debug, Error)]
 pub enum Error {
 	#[error("set token is empty")]
 	TokenEmpty,
+	#[error("invalid proxy: {0}")]
+	ProxyInvalid(String),
 	#[error("request error: {0}")]
-	RequestError(#[from] reqwest::Error),
+	RequestError(reqwest::Error),
+	/// 请求超时, 包括连接、读取和调用方设置的超时
+	#[error("request timed out")]
+	Timeout,
 	#[error("middleware error: {0}")]
-	MiddlewareError(#[from] reqwest_middleware::Error),
+	MiddlewareError(reqwest_middleware::Error),
 	#[error("url parse error: {0}")]
 	URLParseError(#[from] url::ParseError),
+	#[error("header name error: {0}")]
+	HeaderNameError(#[from] reqwest::header::InvalidHeaderName),
+	#[error("header value error: {0}")]
+	HeaderValueError(#[from] reqwest::header::InvalidHeaderValue),
+	#[error("io error: {0}")]
+	Io(#[from] std::io::Error),
 	#[error("not found")]
 	NotFound,
 	#[error("unauthorized")]
 	Unauthorized,
-	#[error("rate limit")]
-	RateLimit,
+	#[error("rate limit exceeded")]
+	RateLimit(RateLimitInfo),
+	#[error("{platform} does not support option: {option}")]
+	Unsupported { platform: &'static str, option: String },
+	#[error("failed to decode {entity}.{field}: {source}")]
+	Decode {
+		entity: &'static str,
+		field: String,
+		#[source]
+		source: DecodeError,
+	},
+	/// 平台返回的非成功响应, 字段由各平台的错误响应体解析而来
+	#[error("api error {status}: {message}")]
+	Api {
+		/// HTTP 状态码
+		status: StatusCode,
+		/// 平台的业务错误码
+		code: Option<String>,
+		/// 平台返回的错误信息, 响应体无法解析时为原始响应体或状态码描述
+		message: String,
+		/// 平台提供的错误文档地址
+		documentation_url: Option<String>,
+		/// 平台返回的请求 id, 便于向平台反馈问题
+		request_id: Option<String>,
+	},
+}
+
+/// 从平台错误响应体中解析出的信息, 由各平台转换后用于构造 [Error::Api]
+#[derive(Debug, Clone, Default)]
+pub struct ApiErrorBody {
+	pub code: Option<String>,
+	pub message: Option<String>,
+	pub documentation_url: Option<String>,
+}
+
+impl Error {
+	/// 由非成功响应构造 [Error::Api]
+	///
+	/// `body` 中没有错误信息时, 依次使用原始响应体和状态码描述
+	pub fn api(
+		status: StatusCode,
+		request_id: Option<String>,
+		body: ApiErrorBody,
+		raw_body: &[u8],
+	) -> Self {
+		let message = body
+			.message
+			.filter(|message| !message.is_empty())
+			.or_else(|| {
+				let raw = String::from_utf8_lossy(raw_body).trim().to_string();
+				(!raw.is_empty()).then_some(raw)
+			})
+			.unwrap_or_else(|| status.canonical_reason().unwrap_or_default().to_string());
+		Error::Api {
+			status,
+			code: body.code,
+			message,
+			documentation_url: body.documentation_url,
+			request_id,
+		}
+	}
+}
+
+impl From<reqwest::Error> for Error {
+	/// 错误中的请求地址会隐藏访问令牌, 避免写入日志
+	fn from(mut err: reqwest::Error) -> Self {
+		if err.is_timeout() {
+			return Error::Timeout;
+		}
+		if let Some(url) = err.url_mut() {
+			redact_url(url);
+		}
+		Error::RequestError(err)
+	}
+}
+
+impl From<reqwest_middleware::Error> for Error {
+	/// 中间件产生的 [Error] 会被还原, 请求本身的错误按 [From<reqwest::Error>] 转换
+	fn from(err: reqwest_middleware::Error) -> Self {
+		match err {
+			reqwest_middleware::Error::Middleware(err) => match err.downcast::<Error>() {
+				Ok(err) => err,
+				Err(err) => Error::MiddlewareError(reqwest_middleware::Error::Middleware(err)),
+			},
+			reqwest_middleware::Error::Reqwest(err) => err.into(),
+		}
+	}
+}
+
+/// 隐藏 URL 查询参数中的访问令牌
+pub(crate) fn redact_url(url: &mut Url) {
+	if url.query().is_none() {
+		return;
+	}
+	let pairs: Vec<(String, String)> = url
+		.query_pairs()
+		.map(|(key, value)| {
+			let value = if REDACTED_PARAMS.contains(&key.as_ref()) { "***".into() } else { value };
+			(key.into_owned(), value.into_owned())
+		})
+		.collect();
+	url.query_pairs_mut().clear().extend_pairs(pairs);
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn redacted(url: &str) -> String {
+		let mut url = Url::parse(url).unwrap();
+		redact_url(&mut url);
+		url.to_string()
+	}
+
+	#[test]
+	fn hides_token_params() {
+		assert_eq!(
+			redacted("https://gitee.com/api/v5/user?access_token=secret&page=2"),
+			"https://gitee.com/api/v5/user?access_token=***&page=2"
+		);
+		assert_eq!(
+			redacted("https://example.com/api?private_token=a&token=b"),
+			"https://example.com/api?private_token=***&token=***"
+		);
+	}
+
+	#[test]
+	fn keeps_other_urls_unchanged() {
+		assert_eq!(redacted("https://api.github.com/user"), "https://api.github.com/user");
+		assert_eq!(
+			redacted("https://api.github.com/user/repos?per_page=30&page=1"),
+			"https://api.github.com/user/repos?per_page=30&page=1"
+		);
+	}
 }
@@ -1,10 +1,18 @@
+mod builder;
+pub mod cache;
 mod client;
+pub mod config;
+pub mod decode;
 pub mod error;
+pub mod metrics;
+pub mod middleware;
 
-pub use client::Client;
+pub use builder::{ClientBuilder, FromConfig};
+pub use client::{Client, with_timeout};
 pub use error::Error;
 pub mod option;
+pub mod pagination;
 pub mod types;
 
 pub type Result<T> = std::result::Result<T, Error>;
-pub use types::collaborator::CollaboratorPermission;
+pub use types::{collaborator::CollaboratorPermission, comment::CommentTarget};
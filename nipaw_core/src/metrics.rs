@@ -0,0 +1,49 @@
+//! 请求指标
+//!
+//! 设置 [ClientConfig::metrics](crate::config::ClientConfig::metrics) 后, 每个 HTTP 请求完成时
+//! 都会调用 [MetricsSink::record], 可在其中接入 Prometheus 等监控系统
+
+use crate::types::rate_limit::RateLimitInfo;
+use reqwest::{Method, StatusCode};
+use std::{fmt::Debug, time::Duration};
+
+/// 请求对应的 [Client](crate::Client) 方法, 由平台客户端通过请求扩展传递给中间件
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Operation(pub &'static str);
+
+/// 单个 HTTP 请求的指标
+#[derive(Debug, Clone)]
+pub struct RequestMetrics {
+	/// 平台名称, 如 `GitHub`
+	pub platform: &'static str,
+	/// 请求对应的 [Client](crate::Client) 方法, 如 `get_repo_info`, 未知时为 `unknown`
+	pub operation: &'static str,
+	/// 请求方法
+	pub method: Method,
+	/// 响应状态码, 请求未得到响应时为 `None`
+	pub status: Option<StatusCode>,
+	/// 请求耗时
+	pub latency: Duration,
+	/// 响应头中的限流信息
+	pub rate_limit: RateLimitInfo,
+}
+
+impl RequestMetrics {
+	/// 状态码分类, 如 `2xx`, `4xx`, 请求未得到响应时为 `error`
+	pub fn status_class(&self) -> &'static str {
+		match self.status.map(|status| status.as_u16() / 100) {
+			Some(1) => "1xx",
+			Some(2) => "2xx",
+			Some(3) => "3xx",
+			Some(4) => "4xx",
+			Some(5) => "5xx",
+			_ => "error",
+		}
+	}
+}
+
+/// 指标接收器
+pub trait MetricsSink: Debug + Send + Sync {
+	/// 记录一个已完成的请求, 在请求所在的任务中同步调用, 不应阻塞
+	fn record(&self, metrics: &RequestMetrics);
+}
@@ -0,0 +1,17 @@
+mod cache;
+mod metrics;
+mod retry;
+mod throttle;
+mod timeout;
+#[cfg(feature = "tracing")]
+mod trace;
+pub use cache::CacheMiddleware;
+pub use metrics::MetricsMiddleware;
+/// 自定义中间件需要实现的 trait, 详见 [ClientBuilder::middleware](crate::ClientBuilder::middleware)
+pub use reqwest_middleware::{Middleware, Next};
+pub use retry::RetryMiddleware;
+pub use throttle::ThrottleMiddleware;
+pub(crate) use timeout::CALL_DEADLINE;
+pub use timeout::TimeoutMiddleware;
+#[cfg(feature = "tracing")]
+pub use trace::TracingMiddleware;
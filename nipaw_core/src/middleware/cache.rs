@@ -0,0 +1,158 @@
+use crate::cache::{CacheStore, CachedResponse};
+use async_trait::async_trait;
+use http::Extensions;
+use reqwest::{
+	Method, Request, Response, StatusCode,
+	header::{
+		AUTHORIZATION, CONTENT_TYPE, ETAG, HeaderMap, IF_MODIFIED_SINCE, IF_NONE_MATCH,
+		LAST_MODIFIED,
+	},
+};
+use reqwest_middleware::{Middleware, Next, Result};
+use std::sync::Arc;
+
+/// 缓存中间件, 详见 [cache](crate::cache)
+pub struct CacheMiddleware {
+	store: Arc<dyn CacheStore>,
+}
+
+impl CacheMiddleware {
+	pub fn new(store: Arc<dyn CacheStore>) -> Self {
+		Self { store }
+	}
+}
+
+#[async_trait]
+impl Middleware for CacheMiddleware {
+	async fn handle(
+		&self,
+		mut req: Request,
+		extensions: &mut Extensions,
+		next: Next<'_>,
+	) -> Result<Response> {
+		if req.method() != Method::GET {
+			return next.run(req, extensions).await;
+		}
+		let key = cache_key(&req);
+		let cached = self.store.get(&key).await;
+		if let Some(cached) = &cached {
+			let headers = req.headers_mut();
+			if let Some(etag) = cached.etag.as_deref().and_then(|etag| etag.parse().ok()) {
+				headers.entry(IF_NONE_MATCH).or_insert(etag);
+			}
+			if let Some(modified) = cached.last_modified.as_deref().and_then(|m| m.parse().ok()) {
+				headers.entry(IF_MODIFIED_SINCE).or_insert(modified);
+			}
+		}
+
+		let res = next.run(req, extensions).await?;
+		match (res.status(), cached) {
+			(StatusCode::NOT_MODIFIED, Some(cached)) => Ok(into_response(cached)),
+			(StatusCode::OK, _) => {
+				let etag = header_string(res.headers(), ETAG);
+				let last_modified = header_string(res.headers(), LAST_MODIFIED);
+				// 只缓存 JSON 响应, 避免将附件下载等大文件读入内存
+				if etag.is_none() && last_modified.is_none() || !is_json(res.headers()) {
+					return Ok(res);
+				}
+				let headers = res
+					.headers()
+					.iter()
+					.filter_map(|(name, value)| {
+						Some((name.to_string(), value.to_str().ok()?.to_string()))
+					})
+					.collect();
+				let body = res.bytes().await?.to_vec();
+				let cached = CachedResponse { etag, last_modified, headers, body };
+				self.store.put(&key, cached.clone()).await;
+				Ok(into_response(cached))
+			}
+			_ => Ok(res),
+		}
+	}
+}
+
+/// 由 URL 和访问令牌生成缓存键, 避免不同用户共享缓存, 也避免令牌以明文写入缓存
+fn cache_key(req: &Request) -> String {
+	let token = req.headers().get(AUTHORIZATION).map(|value| value.as_bytes()).unwrap_or_default();
+	let url = req.url().as_str().as_bytes();
+	format!("{:016x}{:016x}", fnv1a(&[url, token], 0), fnv1a(&[token, url], 1))
+}
+
+/// FNV-1a 哈希, 结果不随 Rust 版本变化, 可用于磁盘缓存的文件名
+fn fnv1a(parts: &[&[u8]], seed: u64) -> u64 {
+	let mut hash = 0xcbf29ce484222325u64 ^ seed;
+	for part in parts {
+		for byte in part.iter().chain(b"\n") {
+			hash ^= u64::from(*byte);
+			hash = hash.wrapping_mul(0x100000001b3);
+		}
+	}
+	hash
+}
+
+fn header_string(headers: &HeaderMap, name: reqwest::header::HeaderName) -> Option<String> {
+	headers.get(name).and_then(|value| value.to_str().ok()).map(str::to_string)
+}
+
+fn is_json(headers: &HeaderMap) -> bool {
+	headers
+		.get(CONTENT_TYPE)
+		.and_then(|value| value.to_str().ok())
+		.is_some_and(|value| value.contains("json"))
+}
+
+fn into_response(cached: CachedResponse) -> Response {
+	let mut builder = http::Response::builder().status(StatusCode::OK);
+	for (name, value) in &cached.headers {
+		builder = builder.header(name, value);
+	}
+	// 响应头均来自合法的响应, 构建不会失败
+	builder.body(cached.body).map(Response::from).unwrap_or_else(|_| {
+		Response::from(http::Response::new(reqwest::Body::from(Vec::<u8>::new())))
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use reqwest::{Method, Url};
+
+	fn request(url: &str, token: Option<&'static str>) -> Request {
+		let mut req = Request::new(Method::GET, Url::parse(url).unwrap());
+		if let Some(token) = token {
+			req.headers_mut().insert(AUTHORIZATION, token.parse().unwrap());
+		}
+		req
+	}
+
+	#[test]
+	fn key_is_stable_and_hides_token() {
+		let key = cache_key(&request("https://api.github.com/user", Some("Bearer secret")));
+		assert_eq!(key, cache_key(&request("https://api.github.com/user", Some("Bearer secret"))));
+		assert_eq!(key.len(), 32);
+		assert!(key.chars().all(|c| c.is_ascii_hexdigit()));
+		assert!(!key.contains("secret"));
+	}
+
+	#[test]
+	fn key_differs_by_url_and_token() {
+		let url = "https://api.github.com/user/repos?page=1";
+		let keys = [
+			cache_key(&request(url, None)),
+			cache_key(&request(url, Some("Bearer alice"))),
+			cache_key(&request(url, Some("Bearer bob"))),
+			cache_key(&request("https://api.github.com/user/repos?page=2", None)),
+		];
+		for (i, key) in keys.iter().enumerate() {
+			assert!(keys[i + 1..].iter().all(|other| other != key), "{keys:?}");
+		}
+	}
+
+	#[test]
+	fn fnv1a_separates_parts() {
+		// 与 FNV-1a 64 位对 "a\n" 的标准结果一致, 结果不能随版本变化
+		assert_eq!(fnv1a(&[b"a"], 0), 0x089bdc07b544e7b2);
+		assert_ne!(fnv1a(&[b"ab", b""], 0), fnv1a(&[b"a", b"b"], 0));
+	}
+}
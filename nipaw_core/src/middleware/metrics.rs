@@ -0,0 +1,51 @@
+use crate::{
+	metrics::{MetricsSink, Operation, RequestMetrics},
+	types::rate_limit::RateLimitInfo,
+};
+use async_trait::async_trait;
+use http::Extensions;
+use reqwest::{Request, Response};
+use reqwest_middleware::{Middleware, Next, Result};
+use std::{sync::Arc, time::Instant};
+
+/// 指标中间件, 详见 [metrics](crate::metrics)
+///
+/// 应在平台中间件之后添加, 使每次重试都被单独记录, 并记录平台返回的原始状态码
+pub struct MetricsMiddleware {
+	platform: &'static str,
+	sink: Arc<dyn MetricsSink>,
+}
+
+impl MetricsMiddleware {
+	pub fn new(platform: &'static str, sink: Arc<dyn MetricsSink>) -> Self {
+		Self { platform, sink }
+	}
+}
+
+#[async_trait]
+impl Middleware for MetricsMiddleware {
+	async fn handle(
+		&self,
+		req: Request,
+		extensions: &mut Extensions,
+		next: Next<'_>,
+	) -> Result<Response> {
+		let operation = extensions.get::<Operation>().map_or("unknown", |operation| operation.0);
+		let method = req.method().clone();
+		let started_at = Instant::now();
+		let result = next.run(req, extensions).await;
+		let (status, rate_limit) = match &result {
+			Ok(res) => (Some(res.status()), RateLimitInfo::from_headers(res.headers())),
+			Err(_) => (None, RateLimitInfo::default()),
+		};
+		self.sink.record(&RequestMetrics {
+			platform: self.platform,
+			operation,
+			method,
+			status,
+			latency: started_at.elapsed(),
+			rate_limit,
+		});
+		result
+	}
+}
@@ -0,0 +1,237 @@
+use crate::Error as CoreError;
+use crate::config::RetryPolicy;
+use async_trait::async_trait;
+use chrono::Utc;
+use http::Extensions;
+use reqwest::{Request, Response, StatusCode};
+use reqwest_middleware::{Error, Middleware, Next, Result};
+use std::time::Duration;
+
+/// 重试中间件, 只重试 [RetryPolicy::methods] 中的请求方法
+pub struct RetryMiddleware {
+	policy: RetryPolicy,
+}
+
+impl RetryMiddleware {
+	pub fn new(policy: RetryPolicy) -> Self {
+		Self { policy }
+	}
+
+	/// 计算第 `attempt` 次重试前的等待时间, 返回 `None` 表示不再重试
+	fn retry_delay(&self, result: &Result<Response>, attempt: u32) -> Option<Duration> {
+		match classify(result)? {
+			Retry::Backoff => Some(self.backoff(attempt)),
+			Retry::After(wait) => (wait <= self.policy.max_delay).then_some(wait),
+		}
+	}
+
+	fn backoff(&self, attempt: u32) -> Duration {
+		let delay = self
+			.policy
+			.base_delay
+			.saturating_mul(2u32.saturating_pow(attempt))
+			.min(self.policy.max_delay);
+		if self.policy.jitter {
+			// 保留一半的间隔, 另一半随机, 避免多个请求同时重试
+			let half = delay / 2;
+			half + half.mul_f64(fastrand::f64())
+		} else {
+			delay
+		}
+	}
+}
+
+#[async_trait]
+impl Middleware for RetryMiddleware {
+	async fn handle(
+		&self,
+		req: Request,
+		extensions: &mut Extensions,
+		next: Next<'_>,
+	) -> Result<Response> {
+		if !self.policy.methods.contains(req.method()) {
+			return next.run(req, extensions).await;
+		}
+		let mut attempt = 0;
+		loop {
+			let Some(request) = req.try_clone() else {
+				return next.run(req, extensions).await;
+			};
+			let result = next.clone().run(request, extensions).await;
+			if attempt >= self.policy.max_retries {
+				return result;
+			}
+			let Some(delay) = self.retry_delay(&result, attempt) else {
+				return result;
+			};
+			attempt += 1;
+			tokio::time::sleep(delay).await;
+		}
+	}
+}
+
+enum Retry {
+	/// 按重试策略退避
+	Backoff,
+	/// 按平台给出的时间等待
+	After(Duration),
+}
+
+fn classify(result: &Result<Response>) -> Option<Retry> {
+	match result {
+		Ok(res) => is_transient_status(res.status()).then_some(Retry::Backoff),
+		Err(Error::Reqwest(err)) => {
+			(err.is_connect() || err.is_timeout() || err.is_request()).then_some(Retry::Backoff)
+		}
+		// 平台中间件会将非成功响应转换为 [CoreError]
+		Err(Error::Middleware(err)) => match err.downcast_ref::<CoreError>() {
+			Some(CoreError::Api { status, .. }) => {
+				is_transient_status(*status).then_some(Retry::Backoff)
+			}
+			Some(CoreError::RateLimit(rate_limit)) => {
+				let reset_in = rate_limit
+					.reset_at
+					.map(|reset_at| (reset_at - Utc::now()).to_std().unwrap_or_default());
+				Some(rate_limit.retry_after.or(reset_in).map_or(Retry::Backoff, Retry::After))
+			}
+			_ => None,
+		},
+	}
+}
+
+fn is_transient_status(status: StatusCode) -> bool {
+	matches!(
+		status,
+		StatusCode::BAD_GATEWAY | StatusCode::SERVICE_UNAVAILABLE | StatusCode::GATEWAY_TIMEOUT
+	)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::error::ApiErrorBody;
+	use crate::types::rate_limit::RateLimitInfo;
+	use reqwest::Method;
+	use std::sync::{
+		Arc,
+		atomic::{AtomicU32, Ordering},
+	};
+
+	fn policy(jitter: bool) -> RetryPolicy {
+		RetryPolicy {
+			base_delay: Duration::from_millis(100),
+			max_delay: Duration::from_secs(1),
+			jitter,
+			..Default::default()
+		}
+	}
+
+	fn response(status: StatusCode) -> Result<Response> {
+		Ok(Response::from(http::Response::builder().status(status).body("").unwrap()))
+	}
+
+	fn rate_limit(retry_after: Option<Duration>) -> Result<Response> {
+		let rate_limit = RateLimitInfo { retry_after, ..Default::default() };
+		Err(Error::middleware(CoreError::RateLimit(rate_limit)))
+	}
+
+	#[test]
+	fn backoff_doubles_up_to_max_delay() {
+		let retry = RetryMiddleware::new(policy(false));
+		assert_eq!(retry.backoff(0), Duration::from_millis(100));
+		assert_eq!(retry.backoff(1), Duration::from_millis(200));
+		assert_eq!(retry.backoff(3), Duration::from_millis(800));
+		assert_eq!(retry.backoff(4), Duration::from_secs(1));
+		assert_eq!(retry.backoff(u32::MAX), Duration::from_secs(1));
+	}
+
+	#[test]
+	fn jitter_keeps_at_least_half_the_delay() {
+		let retry = RetryMiddleware::new(policy(true));
+		for attempt in 0..6 {
+			let delay = RetryMiddleware::new(policy(false)).backoff(attempt);
+			for _ in 0..100 {
+				let jittered = retry.backoff(attempt);
+				assert!(jittered >= delay / 2 && jittered <= delay, "{jittered:?} of {delay:?}");
+			}
+		}
+	}
+
+	#[test]
+	fn retries_only_transient_statuses() {
+		for status in
+			[StatusCode::BAD_GATEWAY, StatusCode::SERVICE_UNAVAILABLE, StatusCode::GATEWAY_TIMEOUT]
+		{
+			assert!(matches!(classify(&response(status)), Some(Retry::Backoff)));
+		}
+		for status in [StatusCode::OK, StatusCode::NOT_FOUND, StatusCode::INTERNAL_SERVER_ERROR] {
+			assert!(classify(&response(status)).is_none());
+		}
+	}
+
+	#[test]
+	fn retries_transient_api_errors() {
+		let api = |status| {
+			Err(Error::middleware(CoreError::api(status, None, ApiErrorBody::default(), b"")))
+		};
+		assert!(matches!(classify(&api(StatusCode::SERVICE_UNAVAILABLE)), Some(Retry::Backoff)));
+		assert!(classify(&api(StatusCode::UNPROCESSABLE_ENTITY)).is_none());
+		assert!(classify(&Err(Error::middleware(CoreError::NotFound))).is_none());
+	}
+
+	#[test]
+	fn rate_limit_waits_for_retry_after() {
+		let retry = RetryMiddleware::new(policy(false));
+		let wait = Duration::from_millis(500);
+		assert!(
+			matches!(classify(&rate_limit(Some(wait))), Some(Retry::After(after)) if after == wait)
+		);
+		assert_eq!(retry.retry_delay(&rate_limit(Some(wait)), 0), Some(wait));
+		// 平台要求等待的时间超过最长间隔时不再重试
+		assert_eq!(retry.retry_delay(&rate_limit(Some(Duration::from_secs(60))), 0), None);
+		// 没有给出等待时间时按策略退避
+		assert_eq!(retry.retry_delay(&rate_limit(None), 2), Some(Duration::from_millis(400)));
+	}
+
+	/// 记录请求次数并始终返回 503 的终端中间件
+	struct Unavailable(Arc<AtomicU32>);
+
+	#[async_trait]
+	impl Middleware for Unavailable {
+		async fn handle(
+			&self,
+			_req: Request,
+			_extensions: &mut Extensions,
+			_next: Next<'_>,
+		) -> Result<Response> {
+			self.0.fetch_add(1, Ordering::SeqCst);
+			response(StatusCode::SERVICE_UNAVAILABLE)
+		}
+	}
+
+	fn attempts(method: Method) -> u32 {
+		let count = Arc::new(AtomicU32::new(0));
+		let policy = RetryPolicy {
+			max_retries: 2,
+			base_delay: Duration::from_millis(1),
+			jitter: false,
+			..Default::default()
+		};
+		let client = reqwest_middleware::ClientBuilder::new(reqwest::Client::new())
+			.with(RetryMiddleware::new(policy))
+			.with(Unavailable(count.clone()))
+			.build();
+		let runtime = tokio::runtime::Builder::new_current_thread().enable_time().build().unwrap();
+		let resp = runtime.block_on(client.request(method, "http://localhost/").send()).unwrap();
+		assert_eq!(resp.status(), StatusCode::SERVICE_UNAVAILABLE);
+		count.load(Ordering::SeqCst)
+	}
+
+	#[test]
+	fn retries_idempotent_methods_only() {
+		assert_eq!(attempts(Method::GET), 3);
+		assert_eq!(attempts(Method::HEAD), 3);
+		assert_eq!(attempts(Method::POST), 1);
+		assert_eq!(attempts(Method::PATCH), 1);
+	}
+}
@@ -0,0 +1,120 @@
+use crate::config::ThrottlePolicy;
+use async_trait::async_trait;
+use http::Extensions;
+use reqwest::{Request, Response};
+use reqwest_middleware::{Middleware, Next, Result};
+use std::{
+	sync::{Mutex, PoisonError},
+	time::{Duration, Instant},
+};
+use tokio::sync::Semaphore;
+
+/// 限流中间件, 按令牌桶控制请求速率, 并限制同时进行的请求数
+pub struct ThrottleMiddleware {
+	bucket: Option<Mutex<TokenBucket>>,
+	in_flight: Option<Semaphore>,
+}
+
+impl ThrottleMiddleware {
+	pub fn new(policy: ThrottlePolicy) -> Self {
+		Self {
+			bucket: policy
+				.requests_per_second
+				.filter(|rate| *rate > 0.0)
+				.map(|rate| Mutex::new(TokenBucket::new(rate, policy.burst.max(1)))),
+			in_flight: policy.max_in_flight.map(|permits| Semaphore::new(permits.max(1))),
+		}
+	}
+
+	async fn acquire_token(&self) {
+		let Some(bucket) = &self.bucket else {
+			return;
+		};
+		loop {
+			let wait = bucket.lock().unwrap_or_else(PoisonError::into_inner).try_acquire();
+			match wait {
+				None => return,
+				Some(wait) => tokio::time::sleep(wait).await,
+			}
+		}
+	}
+}
+
+#[async_trait]
+impl Middleware for ThrottleMiddleware {
+	async fn handle(
+		&self,
+		req: Request,
+		extensions: &mut Extensions,
+		next: Next<'_>,
+	) -> Result<Response> {
+		// 信号量不会被关闭, 获取失败时直接放行
+		let _permit = match &self.in_flight {
+			Some(semaphore) => semaphore.acquire().await.ok(),
+			None => None,
+		};
+		self.acquire_token().await;
+		next.run(req, extensions).await
+	}
+}
+
+struct TokenBucket {
+	rate: f64,
+	capacity: f64,
+	tokens: f64,
+	updated_at: Instant,
+}
+
+impl TokenBucket {
+	fn new(rate: f64, burst: u32) -> Self {
+		let capacity = f64::from(burst);
+		Self { rate, capacity, tokens: capacity, updated_at: Instant::now() }
+	}
+
+	/// 尝试取出一个令牌, 令牌不足时返回需要等待的时间
+	fn try_acquire(&mut self) -> Option<Duration> {
+		let now = Instant::now();
+		let elapsed = now.duration_since(self.updated_at).as_secs_f64();
+		self.tokens = (self.tokens + elapsed * self.rate).min(self.capacity);
+		self.updated_at = now;
+		if self.tokens >= 1.0 {
+			self.tokens -= 1.0;
+			None
+		} else {
+			Some(Duration::from_secs_f64((1.0 - self.tokens) / self.rate))
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn burst_then_wait_for_refill() {
+		let mut bucket = TokenBucket::new(10.0, 2);
+		assert_eq!(bucket.try_acquire(), None);
+		assert_eq!(bucket.try_acquire(), None);
+		let wait = bucket.try_acquire().expect("bucket should be empty");
+		assert!(wait > Duration::ZERO && wait <= Duration::from_millis(100), "{wait:?}");
+	}
+
+	#[test]
+	fn refills_by_elapsed_time() {
+		let mut bucket = TokenBucket::new(10.0, 2);
+		bucket.tokens = 0.0;
+		bucket.updated_at -= Duration::from_millis(150);
+		assert_eq!(bucket.try_acquire(), None);
+		assert!(bucket.try_acquire().is_some());
+	}
+
+	#[test]
+	fn refill_is_capped_at_burst() {
+		let mut bucket = TokenBucket::new(10.0, 2);
+		bucket.tokens = 0.0;
+		bucket.updated_at -= Duration::from_secs(60);
+		assert_eq!(bucket.try_acquire(), None);
+		assert_eq!(bucket.try_acquire(), None);
+		assert!(bucket.try_acquire().is_some());
+	}
+}
@@ -0,0 +1,112 @@
+use crate::Error as CoreError;
+use async_trait::async_trait;
+use http::Extensions;
+use reqwest::{Request, Response};
+use reqwest_middleware::{Error, Middleware, Next, Result};
+use std::time::Instant;
+
+tokio::task_local! {
+	/// 当前调用的截止时间, 由 [with_timeout](crate::with_timeout) 设置
+	pub(crate) static CALL_DEADLINE: Instant;
+}
+
+/// 超时中间件, 将 [with_timeout](crate::with_timeout) 设置的截止时间传递给调用中的每个请求
+///
+/// 应在重试之后添加, 使每次重试只使用剩余的时间
+pub struct TimeoutMiddleware;
+
+#[async_trait]
+impl Middleware for TimeoutMiddleware {
+	async fn handle(
+		&self,
+		mut req: Request,
+		extensions: &mut Extensions,
+		next: Next<'_>,
+	) -> Result<Response> {
+		if let Ok(deadline) = CALL_DEADLINE.try_with(|deadline| *deadline) {
+			let remaining = deadline.saturating_duration_since(Instant::now());
+			if remaining.is_zero() {
+				return Err(Error::middleware(CoreError::Timeout));
+			}
+			let timeout = req.timeout_mut();
+			*timeout = Some(timeout.map_or(remaining, |timeout| timeout.min(remaining)));
+		}
+		next.run(req, extensions).await
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::{
+		sync::{Arc, Mutex},
+		time::Duration,
+	};
+
+	/// 记录请求超时时间的终端中间件
+	struct Capture(Arc<Mutex<Option<Option<Duration>>>>);
+
+	#[async_trait]
+	impl Middleware for Capture {
+		async fn handle(
+			&self,
+			req: Request,
+			_extensions: &mut Extensions,
+			_next: Next<'_>,
+		) -> Result<Response> {
+			*self.0.lock().unwrap() = Some(req.timeout().copied());
+			Ok(Response::from(http::Response::new("")))
+		}
+	}
+
+	/// 在截止时间内发送请求, 返回请求的超时时间, 请求未发出时返回 `None`
+	fn sent_timeout(
+		deadline: Option<Duration>,
+		timeout: Option<Duration>,
+	) -> Option<Option<Duration>> {
+		let captured = Arc::new(Mutex::new(None));
+		let client = reqwest_middleware::ClientBuilder::new(reqwest::Client::new())
+			.with(TimeoutMiddleware)
+			.with(Capture(captured.clone()))
+			.build();
+		let mut request = client.get("http://localhost/");
+		if let Some(timeout) = timeout {
+			request = request.timeout(timeout);
+		}
+		let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+		let result = match deadline {
+			Some(deadline) => {
+				runtime.block_on(CALL_DEADLINE.scope(Instant::now() + deadline, request.send()))
+			}
+			None => runtime.block_on(request.send()),
+		};
+		if result.is_err() {
+			assert!(captured.lock().unwrap().is_none());
+		}
+		captured.lock().unwrap().take()
+	}
+
+	#[test]
+	fn keeps_timeout_outside_with_timeout() {
+		assert_eq!(sent_timeout(None, None), Some(None));
+		assert_eq!(
+			sent_timeout(None, Some(Duration::from_secs(3))),
+			Some(Some(Duration::from_secs(3)))
+		);
+	}
+
+	#[test]
+	fn uses_the_shorter_of_timeout_and_deadline() {
+		let secs = Duration::from_secs;
+		assert_eq!(sent_timeout(Some(secs(60)), Some(secs(3))), Some(Some(secs(3))));
+		let timeout = sent_timeout(Some(secs(3)), Some(secs(60))).flatten().unwrap();
+		assert!(timeout <= secs(3) && timeout > secs(2), "{timeout:?}");
+		let timeout = sent_timeout(Some(secs(3)), None).flatten().unwrap();
+		assert!(timeout <= secs(3) && timeout > secs(2), "{timeout:?}");
+	}
+
+	#[test]
+	fn expired_deadline_fails_without_sending() {
+		assert_eq!(sent_timeout(Some(Duration::ZERO), None), None);
+	}
+}
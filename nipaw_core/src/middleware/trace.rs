@@ -0,0 +1,64 @@
+use crate::{error::redact_url, metrics::Operation, types::rate_limit::RateLimitInfo};
+use async_trait::async_trait;
+use http::Extensions;
+use reqwest::{Request, Response};
+use reqwest_middleware::{Middleware, Next, Result};
+use std::time::Instant;
+use tracing::{Instrument, field};
+
+/// 请求追踪中间件, 为每个 HTTP 请求生成 span, 记录状态码、耗时和剩余请求数
+///
+/// span 只记录接口名称和主机名, 不记录包含路径参数和访问令牌的完整地址
+///
+/// 应在平台中间件之后添加, 使每次重试都有独立的 span, 并记录平台返回的原始状态码
+pub struct TracingMiddleware {
+	platform: &'static str,
+}
+
+impl TracingMiddleware {
+	pub fn new(platform: &'static str) -> Self {
+		Self { platform }
+	}
+}
+
+#[async_trait]
+impl Middleware for TracingMiddleware {
+	async fn handle(
+		&self,
+		req: Request,
+		extensions: &mut Extensions,
+		next: Next<'_>,
+	) -> Result<Response> {
+		let operation = extensions.get::<Operation>().map_or("unknown", |operation| operation.0);
+		let span = tracing::debug_span!(
+			"http_request",
+			platform = self.platform,
+			operation,
+			method = %req.method(),
+			host = req.url().host_str().unwrap_or_default(),
+			status = field::Empty,
+			latency_ms = field::Empty,
+			rate_limit_remaining = field::Empty,
+		);
+		let started_at = Instant::now();
+		let mut result = next.run(req, extensions).instrument(span.clone()).await;
+		// 请求错误的描述包含完整地址, 记录和返回前隐藏其中的访问令牌
+		if let Err(reqwest_middleware::Error::Reqwest(err)) = &mut result
+			&& let Some(url) = err.url_mut()
+		{
+			redact_url(url);
+		}
+		span.record("latency_ms", started_at.elapsed().as_millis() as u64);
+		match &result {
+			Ok(res) => {
+				span.record("status", res.status().as_u16());
+				if let Some(remaining) = RateLimitInfo::from_headers(res.headers()).remaining {
+					span.record("rate_limit_remaining", remaining);
+				}
+				span.in_scope(|| tracing::debug!("request finished"));
+			}
+			Err(err) => span.in_scope(|| tracing::warn!(error = %err, "request failed")),
+		}
+		result
+	}
+}
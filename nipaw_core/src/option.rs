@@ -1,7 +1,9 @@
 use chrono::{DateTime, Utc};
 use serde::Deserialize;
+use std::time::Duration;
+use strum::{Display, EnumString, IntoStaticStr};
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Default, Deserialize)]
 pub struct ReposListOptions {
 	/// 每页数量，默认 30，最大 100
 	#[serde(default = "default_per_page")]
@@ -9,17 +11,112 @@ pub struct ReposListOptions {
 	/// 页码，默认 1
 	#[serde(default = "default_page")]
 	pub page: Option<u32>,
+	/// 筛选仓库可见性, 仅获取当前用户仓库时可用, 详见 [VisibilityFilter]
+	pub visibility: Option<VisibilityFilter>,
+	/// 筛选与用户的关系, 详见 [Affiliation]
+	pub affiliation: Option<Affiliation>,
+	/// 筛选仓库类型, 不能与 `visibility` 或 `affiliation` 同时使用, 详见 [RepoType]
+	#[serde(rename = "type")]
+	pub repo_type: Option<RepoType>,
+	/// 排序字段, 未设置时使用各平台原有的默认排序, 详见 [RepoSort]
+	pub sort: Option<RepoSort>,
+	/// 排序方向, 详见 [Direction]
+	pub direction: Option<Direction>,
+	/// 按仓库名称搜索
+	pub search: Option<String>,
+	/// 本次调用每个请求的超时时间, 覆盖客户端配置的 [timeout](crate::config::ClientConfig::timeout)
+	#[serde(skip)]
+	pub timeout: Option<Duration>,
 }
 
+/// 仓库可见性筛选
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Display, EnumString, IntoStaticStr)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum VisibilityFilter {
+	/// 全部仓库
+	All,
+	/// 公开仓库
+	Public,
+	/// 私有仓库
+	Private,
+}
+
+/// 用户与仓库的关系
+///
+/// CNB 只支持 `Owner`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Display, EnumString, IntoStaticStr)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum Affiliation {
+	/// 用户拥有的仓库
+	Owner,
+	/// 用户作为协作者的仓库
+	Collaborator,
+	/// 用户所在组织的仓库
+	OrganizationMember,
+}
+
+/// 用户仓库类型
+///
+/// GitHub 获取指定用户仓库时只支持 `All`, `Owner` 和 `Member`, CNB 不支持此筛选
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Display, EnumString, IntoStaticStr)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum RepoType {
+	/// 全部仓库
+	All,
+	/// 用户拥有的仓库
+	Owner,
+	/// 公开仓库
+	Public,
+	/// 私有仓库
+	Private,
+	/// 用户作为成员参与的仓库
+	Member,
+}
+
+const DEFAULT_PER_PAGE: u32 = 30;
+const MAX_PER_PAGE: u32 = 100;
+
 fn default_per_page() -> Option<u32> {
-	Some(30)
+	Some(DEFAULT_PER_PAGE)
 }
 
 fn default_page() -> Option<u32> {
 	Some(1)
 }
 
-#[derive(Debug, Deserialize)]
+macro_rules! impl_page_options {
+	($($options:ty),*) => {
+		$(
+			impl $options {
+				/// 每页数量, 未设置时为 30, 最大 100
+				pub fn per_page(&self) -> u32 {
+					self.per_page.unwrap_or(DEFAULT_PER_PAGE).clamp(1, MAX_PER_PAGE)
+				}
+
+				/// 页码, 未设置时为 1
+				pub fn page(&self) -> u32 {
+					self.page.unwrap_or(1).max(1)
+				}
+			}
+		)*
+	};
+}
+
+impl_page_options!(
+	ReposListOptions,
+	CommitListOptions,
+	OrgRepoListOptions,
+	BranchListOptions,
+	TagListOptions,
+	ReleaseListOptions,
+	IssueListOptions,
+	CommentListOptions
+);
+
+#[derive(Debug, Clone, Default, Deserialize)]
 pub struct CommitListOptions {
 	/// 每页数量，默认 30，最大 100
 	#[serde(default = "default_per_page")]
@@ -35,9 +132,12 @@ pub struct CommitListOptions {
 	pub since: Option<DateTime<Utc>>,
 	/// 筛选到此时间结束
 	pub until: Option<DateTime<Utc>>,
+	/// 本次调用每个请求的超时时间, 覆盖客户端配置的 [timeout](crate::config::ClientConfig::timeout)
+	#[serde(skip)]
+	pub timeout: Option<Duration>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Default, Deserialize)]
 pub struct OrgRepoListOptions {
 	/// 每页数量，默认 30，最大 100
 	#[serde(default = "default_per_page")]
@@ -45,4 +145,258 @@ pub struct OrgRepoListOptions {
 	/// 页码，默认 1
 	#[serde(default = "default_page")]
 	pub page: Option<u32>,
+	/// 筛选仓库类型，默认全部, 详见 [OrgRepoType]
+	#[serde(rename = "type")]
+	pub repo_type: Option<OrgRepoType>,
+	/// 排序字段, 详见 [RepoSort]
+	pub sort: Option<RepoSort>,
+	/// 排序方向, 详见 [Direction]
+	pub direction: Option<Direction>,
+	/// 本次调用每个请求的超时时间, 覆盖客户端配置的 [timeout](crate::config::ClientConfig::timeout)
+	#[serde(skip)]
+	pub timeout: Option<Duration>,
+}
+
+/// 组织仓库类型
+///
+/// Gitee 和 GitCode 只支持 `All`, `Public` 和 `Private`,
+/// CNB 不支持 `Forks`, `Sources` 和 `Member`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Display, EnumString, IntoStaticStr)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum OrgRepoType {
+	/// 全部仓库
+	All,
+	/// 公开仓库
+	Public,
+	/// 私有仓库
+	Private,
+	/// fork 的仓库
+	Forks,
+	/// 非 fork 的仓库
+	Sources,
+	/// 作为成员参与的仓库
+	Member,
+}
+
+/// 仓库排序字段
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Display, EnumString, IntoStaticStr)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RepoSort {
+	/// 创建时间
+	Created,
+	/// 更新时间
+	Updated,
+	/// 推送时间
+	Pushed,
+	/// 仓库全名
+	FullName,
+}
+
+/// 排序方向
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Display, EnumString, IntoStaticStr)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum Direction {
+	/// 升序
+	Asc,
+	/// 降序
+	Desc,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct BranchListOptions {
+	/// 每页数量，默认 30，最大 100
+	#[serde(default = "default_per_page")]
+	pub per_page: Option<u32>,
+	/// 页码，默认 1
+	#[serde(default = "default_page")]
+	pub page: Option<u32>,
+	/// 本次调用每个请求的超时时间, 覆盖客户端配置的 [timeout](crate::config::ClientConfig::timeout)
+	#[serde(skip)]
+	pub timeout: Option<Duration>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct TagListOptions {
+	/// 每页数量，默认 30，最大 100
+	#[serde(default = "default_per_page")]
+	pub per_page: Option<u32>,
+	/// 页码，默认 1
+	#[serde(default = "default_page")]
+	pub page: Option<u32>,
+	/// 本次调用每个请求的超时时间, 覆盖客户端配置的 [timeout](crate::config::ClientConfig::timeout)
+	#[serde(skip)]
+	pub timeout: Option<Duration>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ReleaseListOptions {
+	/// 每页数量，默认 30，最大 100
+	#[serde(default = "default_per_page")]
+	pub per_page: Option<u32>,
+	/// 页码，默认 1
+	#[serde(default = "default_page")]
+	pub page: Option<u32>,
+	/// 本次调用每个请求的超时时间, 覆盖客户端配置的 [timeout](crate::config::ClientConfig::timeout)
+	#[serde(skip)]
+	pub timeout: Option<Duration>,
+}
+
+/// 创建发布的选项
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ReleaseOptions {
+	/// 标签名称
+	pub tag_name: String,
+	/// 标签不存在时用于创建标签的分支名或提交SHA, 默认为仓库默认分支
+	pub target_commitish: Option<String>,
+	/// 发布标题, 默认为标签名称
+	pub name: Option<String>,
+	/// 发布说明
+	pub body: Option<String>,
+	/// 是否为草稿, Gitee 和 GitCode 不支持
+	pub draft: Option<bool>,
+	/// 是否为预发布
+	pub prerelease: Option<bool>,
+}
+
+/// 更新发布的选项, 未设置的字段保持不变
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ReleaseUpdateOptions {
+	/// 标签名称
+	pub tag_name: Option<String>,
+	/// 标签不存在时用于创建标签的分支名或提交SHA
+	pub target_commitish: Option<String>,
+	/// 发布标题
+	pub name: Option<String>,
+	/// 发布说明
+	pub body: Option<String>,
+	/// 是否为草稿, Gitee 和 GitCode 不支持
+	pub draft: Option<bool>,
+	/// 是否为预发布
+	pub prerelease: Option<bool>,
+}
+
+impl From<ReleaseOptions> for ReleaseUpdateOptions {
+	fn from(value: ReleaseOptions) -> Self {
+		ReleaseUpdateOptions {
+			tag_name: Some(value.tag_name),
+			target_commitish: value.target_commitish,
+			name: value.name,
+			body: value.body,
+			draft: value.draft,
+			prerelease: value.prerelease,
+		}
+	}
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct IssueListOptions {
+	/// 每页数量，默认 30，最大 100
+	#[serde(default = "default_per_page")]
+	pub per_page: Option<u32>,
+	/// 页码，默认 1
+	#[serde(default = "default_page")]
+	pub page: Option<u32>,
+	/// 筛选状态, 默认只获取开启的 Issue, 详见 [IssueStateFilter]
+	pub state: Option<IssueStateFilter>,
+	/// 筛选标签, 需要同时包含全部标签
+	pub labels: Option<Vec<String>>,
+	/// 筛选指派的用户名
+	pub assignee: Option<String>,
+	/// 筛选创建者用户名
+	pub creator: Option<String>,
+	/// 筛选此时间之后更新的 Issue
+	pub since: Option<DateTime<Utc>>,
+	/// 排序字段, 详见 [IssueSort]
+	pub sort: Option<IssueSort>,
+	/// 排序方向, 详见 [Direction]
+	pub direction: Option<Direction>,
+	/// 本次调用每个请求的超时时间, 覆盖客户端配置的 [timeout](crate::config::ClientConfig::timeout)
+	#[serde(skip)]
+	pub timeout: Option<Duration>,
+}
+
+/// Issue 状态筛选
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Display, EnumString, IntoStaticStr)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum IssueStateFilter {
+	/// 开启的 Issue
+	Open,
+	/// 已关闭的 Issue
+	Closed,
+	/// 全部 Issue
+	All,
+}
+
+/// Issue 排序字段
+///
+/// Gitee、GitCode 和 CNB 不支持 `Comments`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Display, EnumString, IntoStaticStr)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum IssueSort {
+	/// 创建时间
+	Created,
+	/// 更新时间
+	Updated,
+	/// 评论数量
+	Comments,
+}
+
+/// 创建 Issue 的选项
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct IssueOptions {
+	/// 标题
+	pub title: String,
+	/// 内容
+	pub body: Option<String>,
+	/// 标签名称列表
+	pub labels: Option<Vec<String>>,
+	/// 指派的用户名列表, Gitee 和 GitCode 只支持指派一个用户
+	pub assignees: Option<Vec<String>>,
+	/// 里程碑编号, CNB 不支持
+	pub milestone: Option<u64>,
+}
+
+/// 更新 Issue 的选项, 未设置的字段保持不变
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct IssueUpdateOptions {
+	/// 标题
+	pub title: Option<String>,
+	/// 内容
+	pub body: Option<String>,
+	/// 标签名称列表, 会覆盖原有标签
+	pub labels: Option<Vec<String>>,
+	/// 指派的用户名列表, 会覆盖原有指派, Gitee 和 GitCode 只支持指派一个用户
+	pub assignees: Option<Vec<String>>,
+	/// 里程碑编号, CNB 不支持
+	pub milestone: Option<u64>,
+}
+
+impl From<IssueOptions> for IssueUpdateOptions {
+	fn from(value: IssueOptions) -> Self {
+		IssueUpdateOptions {
+			title: Some(value.title),
+			body: value.body,
+			labels: value.labels,
+			assignees: value.assignees,
+			milestone: value.milestone,
+		}
+	}
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct CommentListOptions {
+	/// 每页数量，默认 30，最大 100
+	#[serde(default = "default_per_page")]
+	pub per_page: Option<u32>,
+	/// 页码，默认 1
+	#[serde(default = "default_page")]
+	pub page: Option<u32>,
+	/// 本次调用每个请求的超时时间, 覆盖客户端配置的 [timeout](crate::config::ClientConfig::timeout)
+	#[serde(skip)]
+	pub timeout: Option<Duration>,
 }
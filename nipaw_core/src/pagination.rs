@@ -0,0 +1,291 @@
+use crate::{Error, Result, decode, types::WithRaw};
+use futures::{
+	Stream, StreamExt, TryStreamExt,
+	stream::{self, BoxStream},
+};
+use reqwest::header::{HeaderMap, LINK};
+use reqwest_middleware::RequestBuilder;
+use serde::de::DeserializeOwned;
+use std::{collections::HashMap, future::Future, time::Duration};
+
+/// 单页数据
+#[derive(Debug, Clone)]
+pub struct Page<T> {
+	/// 当前页数据
+	pub items: Vec<T>,
+	/// 下一页页码, 没有下一页时为 `None`
+	pub next_page: Option<u32>,
+}
+
+/// 下一页页码的计算方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NextPage {
+	/// 从 `Link` 响应头中解析, 详见 [next_page_from_link]
+	Link,
+	/// 根据 `total_page` 响应头计算, 缺少该响应头时按当前页数据条数判断, 详见 [next_page_from_total]
+	TotalPage,
+	/// 根据当前页数据条数判断, 详见 [next_page_from_len]
+	Len,
+}
+
+/// 列表接口的分页请求, 各平台只需提供带认证信息的请求和下一页页码的计算方式
+pub trait PagedClient: Sync {
+	/// 平台名称, 用于追踪
+	const PLATFORM: &'static str;
+
+	/// 下一页页码的计算方式
+	const NEXT_PAGE: NextPage;
+
+	/// 每页数量的查询参数名
+	const PER_PAGE_PARAM: &'static str = "per_page";
+
+	/// 创建带认证信息的 GET 请求
+	///
+	/// # 参数
+	///
+	/// * `operation` - 调用的接口名称, 用于指标和追踪
+	/// * `url` - 请求地址
+	fn page_request(&self, operation: &'static str, url: &str) -> RequestBuilder;
+
+	/// 是否在统一类型中保留平台返回的原始 JSON
+	fn include_raw(&self) -> bool;
+
+	/// 请求一页数据, 页码和每页数量从 `params` 中读取
+	///
+	/// # 参数
+	///
+	/// * `operation` - 调用的接口名称
+	/// * `url` - 请求地址
+	/// * `params` - 查询参数
+	/// * `timeout` - 本次请求的超时时间
+	fn get_page<M, T>(
+		&self,
+		operation: &'static str,
+		url: &str,
+		params: &HashMap<&str, String>,
+		timeout: Option<Duration>,
+	) -> impl Future<Output = Result<Page<T>>> + Send
+	where
+		M: DeserializeOwned + Send,
+		T: From<M> + WithRaw + Send,
+	{
+		let mut request = self.page_request(operation, url).query(params);
+		if let Some(timeout) = timeout {
+			request = request.timeout(timeout);
+		}
+		let page = param(params, "page").unwrap_or(1);
+		let per_page = param(params, Self::PER_PAGE_PARAM).unwrap_or(1);
+		let include_raw = self.include_raw();
+		async move {
+			let resp = request.send().await?;
+			let next_page = match Self::NEXT_PAGE {
+				NextPage::Link => Some(next_page_from_link(resp.headers())),
+				NextPage::TotalPage => {
+					total_page(resp.headers()).map(|total| next_page(page, total))
+				}
+				NextPage::Len => None,
+			};
+			let items: Vec<T> = decode::json_list_into::<M, T>(resp, include_raw).await?;
+			let next_page =
+				next_page.unwrap_or_else(|| next_page_from_len(items.len(), per_page, page));
+			Ok(Page { items, next_page })
+		}
+	}
+
+	/// 从 `first_page` 开始依次请求每一页, 详见 [paginate]
+	///
+	/// # 参数
+	///
+	/// * `operation` - 调用的接口名称
+	/// * `url` - 请求地址
+	/// * `params` - 查询参数, 其中的页码会被替换
+	/// * `first_page` - 起始页码
+	/// * `limit` - 最多返回的数据条数
+	/// * `timeout` - 每个请求的超时时间
+	fn paginate<'a, M, T>(
+		&'a self,
+		operation: &'static str,
+		url: String,
+		params: HashMap<&'static str, String>,
+		first_page: u32,
+		limit: Option<usize>,
+		timeout: Option<Duration>,
+	) -> BoxStream<'a, Result<T>>
+	where
+		M: DeserializeOwned + Send + 'a,
+		T: From<M> + WithRaw + Send + 'a,
+	{
+		let items = paginate(first_page, limit, move |page| {
+			let url = url.clone();
+			let mut params = params.clone();
+			params.insert("page", page.to_string());
+			async move { self.get_page::<M, T>(operation, &url, &params, timeout).await }
+		});
+		instrument(items, Self::PLATFORM, operation)
+	}
+}
+
+/// 为数据流生成 span, 覆盖其中每一页的请求
+#[cfg(feature = "tracing")]
+fn instrument<'a, T: Send + 'a>(
+	items: BoxStream<'a, T>,
+	platform: &'static str,
+	operation: &'static str,
+) -> BoxStream<'a, T> {
+	use tracing::Instrument;
+
+	let span = tracing::info_span!("stream", platform, operation);
+	stream::unfold((items, span), |(mut items, span)| async move {
+		let item = items.next().instrument(span.clone()).await?;
+		Some((item, (items, span)))
+	})
+	.boxed()
+}
+
+#[cfg(not(feature = "tracing"))]
+fn instrument<'a, T>(
+	items: BoxStream<'a, T>,
+	_platform: &'static str,
+	_operation: &'static str,
+) -> BoxStream<'a, T> {
+	items
+}
+
+fn param(params: &HashMap<&str, String>, key: &str) -> Option<u32> {
+	params.get(key).and_then(|value| value.parse().ok())
+}
+
+/// 从 `first_page` 开始依次请求每一页, 直到没有下一页、返回空页或达到 `limit` 条数据
+///
+/// # 参数
+///
+/// * `first_page` - 起始页码
+/// * `limit` - 最多返回的数据条数, 为 `None` 时返回全部数据
+/// * `fetch` - 请求指定页码数据的函数
+pub fn paginate<'a, T, F, Fut>(
+	first_page: u32,
+	limit: Option<usize>,
+	fetch: F,
+) -> BoxStream<'a, Result<T>>
+where
+	T: Send + 'a,
+	F: FnMut(u32) -> Fut + Send + 'a,
+	Fut: Future<Output = Result<Page<T>>> + Send + 'a,
+{
+	let pages = stream::try_unfold((Some(first_page), fetch), |(page, mut fetch)| async move {
+		let Some(page) = page else {
+			return Result::Ok(None);
+		};
+		let Page { items, next_page } = fetch(page).await?;
+		let next_page = if items.is_empty() { None } else { next_page };
+		Ok(Some((stream::iter(items.into_iter().map(Ok)), (next_page, fetch))))
+	});
+	limit_stream(pages.try_flatten(), limit)
+}
+
+fn limit_stream<'a, T, S>(items: S, limit: Option<usize>) -> BoxStream<'a, Result<T>>
+where
+	S: Stream<Item = Result<T>> + Send + 'a,
+{
+	match limit {
+		Some(limit) => items.take(limit).boxed(),
+		None => items.boxed(),
+	}
+}
+
+/// 从 `Link` 响应头中解析 `rel="next"` 链接的页码
+pub fn next_page_from_link(headers: &HeaderMap) -> Option<u32> {
+	let link = headers.get(LINK)?.to_str().ok()?;
+	link.split(',').find_map(|part| {
+		let (url, rel) = part.split_once(';')?;
+		if !rel.contains("rel=\"next\"") {
+			return None;
+		}
+		let url = url.trim().trim_start_matches('<').trim_end_matches('>');
+		let url = url::Url::parse(url).ok()?;
+		url.query_pairs().find(|(key, _)| key == "page").and_then(|(_, value)| value.parse().ok())
+	})
+}
+
+/// 根据 `total_page` 响应头计算下一页页码
+///
+/// # 参数
+///
+/// * `headers` - 响应头
+/// * `page` - 当前页码
+pub fn next_page_from_total(headers: &HeaderMap, page: u32) -> Option<u32> {
+	next_page(page, total_page(headers)?)
+}
+
+fn total_page(headers: &HeaderMap) -> Option<u32> {
+	headers.get("total_page")?.to_str().ok()?.parse().ok()
+}
+
+fn next_page(page: u32, total_page: u32) -> Option<u32> {
+	(page < total_page).then_some(page + 1)
+}
+
+/// 根据当前页数据条数判断是否存在下一页, 适用于不返回分页信息的接口
+///
+/// # 参数
+///
+/// * `len` - 当前页数据条数
+/// * `per_page` - 每页数量
+/// * `page` - 当前页码
+pub fn next_page_from_len(len: usize, per_page: u32, page: u32) -> Option<u32> {
+	(len >= per_page as usize).then_some(page + 1)
+}
+
+/// 创建只返回一个错误的数据流, 用于请求参数校验失败的场景
+pub fn error_stream<'a, T: Send + 'a>(err: Error) -> BoxStream<'a, Result<T>> {
+	stream::once(async move { Err(err) }).boxed()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use reqwest::header::HeaderValue;
+
+	fn header_map(name: &'static str, value: &'static str) -> HeaderMap {
+		let mut headers = HeaderMap::new();
+		headers.insert(name, HeaderValue::from_static(value));
+		headers
+	}
+
+	#[test]
+	fn link_returns_next_page() {
+		let headers = header_map(
+			"link",
+			"<https://api.github.com/user/repos?per_page=30&page=3>; rel=\"next\", \
+			 <https://api.github.com/user/repos?per_page=30&page=5>; rel=\"last\"",
+		);
+		assert_eq!(next_page_from_link(&headers), Some(3));
+	}
+
+	#[test]
+	fn link_without_next_ends() {
+		let headers = header_map(
+			"link",
+			"<https://api.github.com/user/repos?page=1>; rel=\"first\", \
+			 <https://api.github.com/user/repos?page=4>; rel=\"prev\"",
+		);
+		assert_eq!(next_page_from_link(&headers), None);
+		assert_eq!(next_page_from_link(&HeaderMap::new()), None);
+	}
+
+	#[test]
+	fn total_page_stops_at_last_page() {
+		let headers = header_map("total_page", "3");
+		assert_eq!(next_page_from_total(&headers, 1), Some(2));
+		assert_eq!(next_page_from_total(&headers, 3), None);
+		assert_eq!(next_page_from_total(&HeaderMap::new(), 1), None);
+		assert_eq!(next_page_from_total(&header_map("total_page", "abc"), 1), None);
+	}
+
+	#[test]
+	fn len_continues_on_full_page() {
+		assert_eq!(next_page_from_len(30, 30, 1), Some(2));
+		assert_eq!(next_page_from_len(29, 30, 1), None);
+		assert_eq!(next_page_from_len(0, 30, 2), None);
+	}
+}
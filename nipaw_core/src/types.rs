@@ -1,5 +1,43 @@
+pub mod branch;
 pub mod collaborator;
+pub mod comment;
 pub mod commit;
+pub mod issue;
 pub mod org;
+pub mod rate_limit;
+pub mod release;
 pub mod repo;
+pub mod tag;
 pub mod user;
+
+use serde_json::Value;
+
+/// 可携带平台原始 JSON 的统一类型
+pub trait WithRaw {
+	/// 设置平台返回的原始 JSON
+	fn set_raw(&mut self, raw: Value);
+}
+
+macro_rules! impl_with_raw {
+	($($ty:ty),*) => {
+		$(
+			impl WithRaw for $ty {
+				fn set_raw(&mut self, raw: Value) {
+					self.raw = Some(raw);
+				}
+			}
+		)*
+	};
+}
+
+impl_with_raw!(
+	repo::RepoInfo,
+	user::UserInfo,
+	org::OrgInfo,
+	commit::CommitInfo,
+	branch::BranchInfo,
+	tag::TagInfo,
+	release::ReleaseInfo,
+	issue::IssueInfo,
+	comment::CommentInfo
+);
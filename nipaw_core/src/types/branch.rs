@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BranchInfo {
+	/// 分支名称
+	pub name: String,
+	/// 分支最新提交的SHA
+	pub sha: String,
+	/// 是否为受保护分支
+	pub protected: bool,
+	/// 平台返回的原始 JSON, 仅在开启 [ClientConfig::include_raw](crate::config::ClientConfig::include_raw) 时填充
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub raw: Option<Value>,
+}
@@ -0,0 +1,33 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommentInfo {
+	/// 评论ID
+	pub id: String,
+	/// 评论内容
+	pub body: String,
+	/// 评论者用户名
+	pub author: Option<String>,
+	/// 评论所在的文件路径, 仅 PR 代码评论存在
+	pub path: Option<String>,
+	/// 评论所在的行号, 仅 PR 代码评论存在
+	pub line: Option<u64>,
+	/// 创建时间
+	pub created_at: DateTime<Utc>,
+	/// 更新时间
+	pub updated_at: Option<DateTime<Utc>>,
+	/// 平台返回的原始 JSON, 仅在开启 [ClientConfig::include_raw](crate::config::ClientConfig::include_raw) 时填充
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub raw: Option<Value>,
+}
+
+/// 评论所属的对象
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CommentTarget {
+	/// Issue
+	Issue,
+	/// PR
+	PullRequest,
+}
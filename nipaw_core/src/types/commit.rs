@@ -1,5 +1,6 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CommitInfo {
@@ -9,6 +10,9 @@ pub struct CommitInfo {
 	pub commit: CommitData,
 	/// 提交统计信息
 	pub stats: StatsInfo,
+	/// 平台返回的原始 JSON, 仅在开启 [ClientConfig::include_raw](crate::config::ClientConfig::include_raw) 时填充
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub raw: Option<Value>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -33,7 +37,7 @@ pub struct UserInfo {
 	pub date: DateTime<Utc>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct StatsInfo {
 	/// 总提交行数
 	pub total: u64,
@@ -0,0 +1,50 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use strum::{Display, EnumString, IntoStaticStr};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueInfo {
+	/// Issue 编号, Gitee 和 GitCode 为字符串形式的编号, 如 `I1ABCD`
+	pub number: String,
+	/// 标题
+	pub title: String,
+	/// 内容
+	pub body: Option<String>,
+	/// 状态
+	pub state: IssueState,
+	/// 标签名称列表
+	pub labels: Vec<String>,
+	/// 指派的用户名列表
+	pub assignees: Vec<String>,
+	/// 里程碑标题
+	pub milestone: Option<String>,
+	/// 创建者用户名
+	pub author: Option<String>,
+	/// 是否为 PR, 仅 GitHub 通过编号获取 Issue 时可能为 PR, Issue 列表不包含 PR
+	pub pull_request: bool,
+	/// 创建时间
+	pub created_at: DateTime<Utc>,
+	/// 更新时间
+	pub updated_at: DateTime<Utc>,
+	/// 关闭时间
+	pub closed_at: Option<DateTime<Utc>>,
+	/// 平台返回的原始 JSON, 仅在开启 [ClientConfig::include_raw](crate::config::ClientConfig::include_raw) 时填充
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub raw: Option<Value>,
+}
+
+/// Issue 状态
+///
+/// Gitee 的 `progressing` 视为 `Open`, `rejected` 视为 `Closed`
+#[derive(
+	Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Display, EnumString, IntoStaticStr,
+)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum IssueState {
+	/// 开启
+	Open,
+	/// 已关闭
+	Closed,
+}
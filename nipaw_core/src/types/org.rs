@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OrgInfo {
@@ -16,4 +17,7 @@ pub struct OrgInfo {
 	pub description: Option<String>,
 	/// 组织关注数
 	pub follow_count: u64,
+	/// 平台返回的原始 JSON, 仅在开启 [ClientConfig::include_raw](crate::config::ClientConfig::include_raw) 时填充
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub raw: Option<Value>,
 }
@@ -0,0 +1,129 @@
+use crate::{Error, Result};
+use chrono::{DateTime, Utc};
+use reqwest::{
+	Response,
+	header::{HeaderMap, RETRY_AFTER},
+};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// 小于该值的重置时间视为相对秒数, 否则视为 Unix 时间戳
+const RESET_DELTA_THRESHOLD: i64 = 1_000_000_000;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RateLimitInfo {
+	/// 当前时间窗口内允许的请求数
+	pub limit: Option<u64>,
+	/// 当前时间窗口内剩余的请求数
+	pub remaining: Option<u64>,
+	/// 时间窗口重置的时间
+	pub reset_at: Option<DateTime<Utc>>,
+	/// 平台建议的重试等待时间, 来自 `Retry-After` 响应头
+	pub retry_after: Option<Duration>,
+}
+
+impl RateLimitInfo {
+	/// 从响应头解析限流信息
+	///
+	/// 依次读取 `x-ratelimit-*` 和 `ratelimit-*` 响应头, `reset` 既可以是 Unix 时间戳也可以是相对秒数
+	pub fn from_headers(headers: &HeaderMap) -> Self {
+		let get = |name: &str| {
+			[format!("x-ratelimit-{name}"), format!("ratelimit-{name}")]
+				.iter()
+				.find_map(|key| headers.get(key.as_str()))
+				.and_then(|value| value.to_str().ok())
+				.and_then(|value| value.trim().parse::<i64>().ok())
+		};
+		let reset_at = get("reset").and_then(|reset| {
+			if reset < RESET_DELTA_THRESHOLD {
+				Some(Utc::now() + chrono::Duration::seconds(reset))
+			} else {
+				DateTime::from_timestamp(reset, 0)
+			}
+		});
+		let retry_after = headers
+			.get(RETRY_AFTER)
+			.and_then(|value| value.to_str().ok())
+			.and_then(|value| value.trim().parse::<u64>().ok())
+			.map(Duration::from_secs);
+		RateLimitInfo {
+			limit: get("limit").and_then(|limit| u64::try_from(limit).ok()),
+			remaining: get("remaining").and_then(|remaining| u64::try_from(remaining).ok()),
+			reset_at,
+			retry_after,
+		}
+	}
+
+	/// 从一次请求的结果中读取限流信息, 请求因限流被拒绝时同样返回限流信息
+	pub fn from_result(
+		result: std::result::Result<Response, reqwest_middleware::Error>,
+	) -> Result<Self> {
+		match result.map_err(Error::from) {
+			Ok(resp) => Ok(Self::from_headers(resp.headers())),
+			Err(Error::RateLimit(rate_limit)) => Ok(rate_limit),
+			Err(err) => Err(err),
+		}
+	}
+
+	/// 响应头是否表明请求因限流被拒绝, 即剩余请求数为 0 或带有 `Retry-After`
+	pub fn is_exhausted(&self) -> bool {
+		self.remaining == Some(0) || self.retry_after.is_some()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use reqwest::header::HeaderValue;
+
+	fn header_map(pairs: &[(&'static str, &'static str)]) -> HeaderMap {
+		let mut headers = HeaderMap::new();
+		for (name, value) in pairs {
+			headers.insert(*name, HeaderValue::from_static(value));
+		}
+		headers
+	}
+
+	#[test]
+	fn parses_github_headers() {
+		let info = RateLimitInfo::from_headers(&header_map(&[
+			("x-ratelimit-limit", "5000"),
+			("x-ratelimit-remaining", "4999"),
+			("x-ratelimit-reset", "1700000000"),
+		]));
+		assert_eq!(info.limit, Some(5000));
+		assert_eq!(info.remaining, Some(4999));
+		assert_eq!(info.reset_at, DateTime::from_timestamp(1_700_000_000, 0));
+		assert_eq!(info.retry_after, None);
+		assert!(!info.is_exhausted());
+	}
+
+	#[test]
+	fn parses_relative_reset_and_retry_after() {
+		let before = Utc::now();
+		let info = RateLimitInfo::from_headers(&header_map(&[
+			("ratelimit-limit", "60"),
+			("ratelimit-remaining", "0"),
+			("ratelimit-reset", "30"),
+			("retry-after", " 12 "),
+		]));
+		assert_eq!(info.limit, Some(60));
+		assert_eq!(info.remaining, Some(0));
+		let reset_at = info.reset_at.expect("relative reset");
+		assert!(reset_at >= before + chrono::Duration::seconds(30));
+		assert!(reset_at <= Utc::now() + chrono::Duration::seconds(30));
+		assert_eq!(info.retry_after, Some(Duration::from_secs(12)));
+		assert!(info.is_exhausted());
+	}
+
+	#[test]
+	fn ignores_missing_and_invalid_headers() {
+		assert_eq!(RateLimitInfo::from_headers(&HeaderMap::new()), RateLimitInfo::default());
+		let info = RateLimitInfo::from_headers(&header_map(&[
+			("x-ratelimit-limit", "abc"),
+			("x-ratelimit-remaining", "-1"),
+			("retry-after", "Wed, 21 Oct 2015 07:28:00 GMT"),
+		]));
+		assert_eq!(info, RateLimitInfo::default());
+	}
+}
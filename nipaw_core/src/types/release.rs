@@ -0,0 +1,112 @@
+use crate::Result;
+use bytes::Bytes;
+use chrono::{DateTime, Utc};
+use futures::stream::BoxStream;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::path::Path;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReleaseInfo {
+	/// 发布ID, GitCode 以标签名称定位发布, 其ID为标签名称
+	pub id: String,
+	/// 标签名称
+	pub tag_name: String,
+	/// 发布指向的分支名或提交SHA
+	pub target_commitish: Option<String>,
+	/// 发布标题
+	pub name: Option<String>,
+	/// 发布说明
+	pub body: Option<String>,
+	/// 是否为草稿
+	pub draft: bool,
+	/// 是否为预发布
+	pub prerelease: bool,
+	/// 发布者用户名
+	pub author: Option<String>,
+	/// 创建时间
+	pub created_at: Option<DateTime<Utc>>,
+	/// 发布时间
+	pub published_at: Option<DateTime<Utc>>,
+	/// 附件列表, 不含平台自动生成的源码包
+	pub assets: Vec<ReleaseAsset>,
+	/// 平台返回的原始 JSON, 仅在开启 [ClientConfig::include_raw](crate::config::ClientConfig::include_raw) 时填充
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub raw: Option<Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReleaseAsset {
+	/// 附件ID, GitCode 没有附件ID, 其ID为附件名称
+	pub id: String,
+	/// 附件名称
+	pub name: String,
+	/// 附件大小, 单位为字节
+	pub size: Option<u64>,
+	/// 附件的 MIME 类型
+	pub content_type: Option<String>,
+	/// 附件下载地址
+	pub download_url: String,
+	/// 下载次数
+	pub download_count: Option<u64>,
+}
+
+/// 附件内容的字节流, 由 [Client::download_release_asset](crate::Client::download_release_asset) 返回
+pub type AssetStream = BoxStream<'static, Result<Bytes>>;
+
+/// 待上传的附件, 内容以流的形式发送, 不会整体读入内存
+#[derive(Debug)]
+pub struct AssetUpload {
+	/// 附件名称
+	pub name: String,
+	/// 附件的 MIME 类型, 默认为 `application/octet-stream`
+	pub content_type: Option<String>,
+	/// 附件大小, 单位为字节, 部分平台要求上传前提供
+	pub size: u64,
+	/// 附件内容
+	pub body: reqwest::Body,
+}
+
+impl AssetUpload {
+	/// 由字节流创建附件
+	///
+	/// # 参数
+	///
+	/// * `name` - 附件名称
+	/// * `size` - 附件大小, 需要与字节流的总长度一致
+	/// * `stream` - 附件内容
+	pub fn from_stream<S>(name: impl Into<String>, size: u64, stream: S) -> Self
+	where
+		S: futures::TryStream + Send + 'static,
+		S::Error: Into<Box<dyn std::error::Error + Send + Sync>>,
+		Bytes: From<S::Ok>,
+	{
+		Self {
+			name: name.into(),
+			content_type: None,
+			size,
+			body: reqwest::Body::wrap_stream(stream),
+		}
+	}
+
+	/// 由本地文件创建附件, 附件名称默认为文件名
+	pub async fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+		let path = path.as_ref();
+		let file = tokio::fs::File::open(path).await?;
+		let size = file.metadata().await?.len();
+		let name =
+			path.file_name().map(|name| name.to_string_lossy().into_owned()).unwrap_or_default();
+		Ok(Self { name, content_type: None, size, body: reqwest::Body::from(file) })
+	}
+
+	/// 设置附件的 MIME 类型
+	pub fn content_type(mut self, content_type: impl Into<String>) -> Self {
+		self.content_type = Some(content_type.into());
+		self
+	}
+
+	/// 附件的 MIME 类型, 未设置时为 `application/octet-stream`
+	pub fn mime(&self) -> &str {
+		self.content_type.as_deref().unwrap_or("application/octet-stream")
+	}
+}
@@ -1,5 +1,6 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use strum::{Display, EnumString, IntoStaticStr};
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -32,6 +33,9 @@ pub struct RepoInfo {
 	pub updated_at: DateTime<Utc>,
 	/// 仓库推送时间
 	pub pushed_at: DateTime<Utc>,
+	/// 平台返回的原始 JSON, 仅在开启 [ClientConfig::include_raw](crate::config::ClientConfig::include_raw) 时填充
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub raw: Option<Value>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Display, EnumString, IntoStaticStr)]
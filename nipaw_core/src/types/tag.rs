@@ -0,0 +1,30 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TagInfo {
+	/// 标签名称
+	pub name: String,
+	/// 标签指向的提交SHA
+	pub sha: String,
+	/// 标签创建者, 仅附注标签存在
+	pub tagger: Option<TaggerInfo>,
+	/// 标签说明, 仅附注标签存在
+	pub message: Option<String>,
+	/// 是否为附注标签
+	pub annotated: bool,
+	/// 平台返回的原始 JSON, 仅在开启 [ClientConfig::include_raw](crate::config::ClientConfig::include_raw) 时填充
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub raw: Option<Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaggerInfo {
+	/// 名称
+	pub name: String,
+	/// 邮箱
+	pub email: Option<String>,
+	/// 创建时间
+	pub date: Option<DateTime<Utc>>,
+}
@@ -1,18 +1,18 @@
 use crate::middleware::AuthMiddleware;
-use reqwest::{
-	Client,
-	header::{HeaderMap, HeaderName, HeaderValue},
-};
-use reqwest_middleware::{ClientBuilder, ClientWithMiddleware};
-use std::sync::{LazyLock, OnceLock};
+use nipaw_core::{Result, config::ClientConfig, middleware::MetricsMiddleware};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use reqwest_middleware::ClientWithMiddleware;
 
-pub(crate) static HTTP_CLIENT: LazyLock<ClientWithMiddleware> = LazyLock::new(|| {
+pub(crate) fn build_http_client(config: &ClientConfig) -> Result<ClientWithMiddleware> {
 	let mut headers = HeaderMap::new();
 	headers.insert(HeaderName::from_static("accept"), HeaderValue::from_static("application/json"));
-	headers.insert(HeaderName::from_static("user-agent"), HeaderValue::from_static("nipaw"));
 
-	let client = Client::builder().default_headers(headers).build().unwrap();
-	ClientBuilder::new(client).with(AuthMiddleware).build()
-});
-
-pub(crate) static PROXY_URL: OnceLock<String> = OnceLock::new();
+	let client = config.http_client_builder(headers)?.build()?;
+	let mut builder = config.middleware_builder(client).with(AuthMiddleware);
+	if let Some(sink) = &config.metrics {
+		builder = builder.with(MetricsMiddleware::new(crate::PLATFORM, sink.clone()));
+	}
+	#[cfg(feature = "tracing")]
+	let builder = builder.with(nipaw_core::middleware::TracingMiddleware::new(crate::PLATFORM));
+	Ok(config.with_user_middlewares(builder).build())
+}
@@ -1,101 +1,86 @@
-use chrono::{NaiveDate, Utc, Weekday};
+use crate::model::{
+	Account, Asset, Branch, Comment, Commit, CommitSignature, CommitStats, Contributions,
+	ErrorBody, Issue, Org, Release, Repo, Tag, User,
+};
+use chrono::{NaiveDate, NaiveTime, Weekday};
 use itertools::Itertools;
+use nipaw_core::error::ApiErrorBody;
+use nipaw_core::types::branch::BranchInfo;
+use nipaw_core::types::comment::CommentInfo;
+use nipaw_core::types::issue::{IssueInfo, IssueState};
+use nipaw_core::types::release::{ReleaseAsset, ReleaseInfo};
+use nipaw_core::types::repo::Visibility;
+use nipaw_core::types::tag::{TagInfo, TaggerInfo};
 use nipaw_core::types::{
-	collaborator::CollaboratorResult,
 	commit::{CommitData, CommitInfo, StatsInfo, UserInfo as CommitUserInfo},
 	org::OrgInfo,
-	repo::{RepoInfo, Visibility},
+	repo::RepoInfo,
 	user::{ContributionData, ContributionResult, UserInfo},
 };
-use serde::{Deserialize, Serialize};
-use serde_json::Value;
-
-#[derive(Serialize, Deserialize)]
-pub(crate) struct JsonValue(pub(crate) Value);
+use nipaw_core::{
+	Error, Result,
+	decode::{DecodeError, entity_name},
+};
 
-impl From<JsonValue> for UserInfo {
-	fn from(json_value: JsonValue) -> Self {
-		let user_info = json_value.0;
+impl From<User> for UserInfo {
+	fn from(user: User) -> Self {
 		UserInfo {
-			id: user_info.get("id").and_then(|v| v.as_u64()).unwrap().to_string(),
-			login: user_info.get("login").and_then(|v| v.as_str()).unwrap().to_string(),
-			name: user_info.get("name").and_then(|v| v.as_str()).map(|s| s.to_string()),
-			avatar_url: user_info.get("avatar_url").and_then(|v| v.as_str()).unwrap().to_string(),
-			email: user_info.get("email").and_then(|v| v.as_str()).map(|s| s.to_string()),
-			followers: user_info.get("followers").and_then(|v| v.as_u64()).unwrap(),
-			following: user_info.get("following").and_then(|v| v.as_u64()).unwrap(),
-			public_repo_count: user_info.get("repo_count").and_then(|v| v.as_u64()).unwrap_or(0),
+			id: user.id.to_string(),
+			login: user.login,
+			name: user.name,
+			avatar_url: user.avatar_url,
+			email: user.email,
+			followers: user.followers,
+			following: user.following,
+			public_repo_count: 0,
+			raw: None,
 		}
 	}
 }
 
-impl From<JsonValue> for RepoInfo {
-	fn from(json_value: JsonValue) -> Self {
-		let repo_info = json_value.0;
-		let is_public = repo_info.get("public").and_then(|v| v.as_bool()).unwrap_or(false);
+impl From<Repo> for RepoInfo {
+	fn from(repo: Repo) -> Self {
+		let is_public = repo.public.unwrap_or(false);
 		RepoInfo {
-			id: repo_info.get("id").and_then(|v| v.as_u64()).unwrap().to_string(),
-			owner: repo_info
-				.get("owner")
-				.and_then(|v| v.get("login"))
-				.and_then(|v| v.as_str())
-				.unwrap()
-				.to_string(),
-			name: repo_info.get("name").and_then(|v| v.as_str()).unwrap().to_string(),
-			full_name: repo_info.get("full_name").and_then(|v| v.as_str()).unwrap().to_string(),
-			description: repo_info
-				.get("description")
-				.and_then(|v| v.as_str())
-				.map(|s| s.to_string()),
+			id: repo.id.to_string(),
+			owner: repo.owner.login,
+			name: repo.name,
+			full_name: repo.full_name,
+			description: repo.description,
 			visibility: if is_public { Visibility::Public } else { Visibility::Private },
-			fork: repo_info.get("fork").and_then(|v| v.as_bool()).unwrap_or(false),
-			fork_count: repo_info.get("forks_count").and_then(|v| v.as_u64()).unwrap_or(0),
-			language: repo_info.get("language").and_then(|v| v.as_str()).map(|s| s.to_string()),
-			star_count: repo_info.get("stargazers_count").and_then(|v| v.as_u64()).unwrap_or(0),
-			default_branch: repo_info
-				.get("default_branch")
-				.and_then(|v| v.as_str())
-				.unwrap()
-				.to_string(),
-			created_at: repo_info
-				.get("created_at")
-				.and_then(|v| v.as_str())
-				.unwrap()
-				.to_string()
-				.parse()
-				.unwrap(),
-			updated_at: repo_info
-				.get("updated_at")
-				.and_then(|v| v.as_str())
-				.unwrap()
-				.to_string()
-				.parse()
-				.unwrap(),
-			pushed_at: repo_info
-				.get("pushed_at")
-				.and_then(|v| v.as_str())
-				.unwrap()
-				.to_string()
-				.parse()
-				.unwrap(),
+			fork: repo.fork.unwrap_or(false),
+			fork_count: repo.forks_count.unwrap_or(0),
+			language: repo.language,
+			star_count: repo.stargazers_count.unwrap_or(0),
+			default_branch: repo.default_branch,
+			created_at: repo.created_at,
+			updated_at: repo.updated_at,
+			pushed_at: repo.pushed_at,
+			raw: None,
 		}
 	}
 }
 
-impl From<JsonValue> for ContributionResult {
-	fn from(value: JsonValue) -> Self {
-		let contribution_result = value.0;
-
-		let contributions: Vec<Vec<ContributionData>> = contribution_result
-			.as_object()
-			.unwrap()
-			.iter()
-			.map(|(date, count)| ContributionData {
-				date: NaiveDate::parse_from_str(date, "%Y-%m-%d")
-					.map(|nd| nd.and_hms_opt(0, 0, 0).unwrap().and_local_timezone(Utc).unwrap())
-					.unwrap(),
-				count: count.as_u64().unwrap() as u32,
+impl TryFrom<Contributions> for ContributionResult {
+	type Error = Error;
+
+	fn try_from(value: Contributions) -> Result<Self> {
+		let contributions: Vec<Vec<ContributionData>> = value
+			.0
+			.into_iter()
+			.map(|(date, count)| {
+				let date = NaiveDate::parse_from_str(&date, "%Y-%m-%d")
+					.map_err(|e| Error::Decode {
+						entity: entity_name::<ContributionResult>(),
+						field: date.clone(),
+						source: DecodeError::InvalidValue(e.to_string()),
+					})?
+					.and_time(NaiveTime::MIN)
+					.and_utc();
+				Ok(ContributionData { date, count: count as u32 })
 			})
+			.collect::<Result<Vec<_>>>()?
+			.into_iter()
 			.sorted_by_key(|c| c.date)
 			.chunk_by(|c| {
 				let naive_date = c.date.naive_utc().date();
@@ -107,93 +92,171 @@ impl From<JsonValue> for ContributionResult {
 
 		let total = contributions.iter().flatten().map(|c| c.count).sum();
 
-		ContributionResult { contributions, total }
+		Ok(ContributionResult { contributions, total })
 	}
 }
 
-impl From<JsonValue> for CommitInfo {
-	fn from(value: JsonValue) -> Self {
-		let commit_info = value.0;
-		let commit_value = commit_info.get("commit").unwrap().clone();
-		let stats_value = commit_info.get("stats").unwrap().clone();
+impl From<Commit> for CommitInfo {
+	fn from(commit: Commit) -> Self {
+		let avatar_url = |account: Option<Account>| {
+			account.map(|account| account.avatar_url).unwrap_or_default()
+		};
 		CommitInfo {
-			sha: commit_info.get("sha").and_then(|v| v.as_str()).unwrap().to_string(),
-			commit: JsonValue(commit_value).into(),
-			stats: JsonValue(stats_value).into(),
+			sha: commit.sha,
+			commit: CommitData {
+				author: commit_user_info(commit.commit.author, avatar_url(commit.author)),
+				committer: commit_user_info(commit.commit.committer, avatar_url(commit.committer)),
+				message: commit.commit.message,
+			},
+			stats: commit.stats.map(Into::into).unwrap_or_default(),
+			raw: None,
 		}
 	}
 }
 
-impl From<JsonValue> for CommitData {
-	fn from(value: JsonValue) -> Self {
-		let commit_data = value.0;
-		let author_value = commit_data.get("author").unwrap().clone();
-		let committer_value = commit_data.get("committer").unwrap().clone();
-		CommitData {
-			author: JsonValue(author_value).into(),
-			committer: JsonValue(committer_value).into(),
-			message: commit_data.get("message").and_then(|v| v.as_str()).unwrap().to_string(),
+fn commit_user_info(signature: CommitSignature, avatar_url: String) -> CommitUserInfo {
+	CommitUserInfo {
+		name: signature.name,
+		email: signature.email,
+		avatar_url,
+		date: signature.date,
+	}
+}
+
+impl From<CommitStats> for StatsInfo {
+	fn from(stats: CommitStats) -> Self {
+		StatsInfo {
+			total: stats.total.unwrap_or(0),
+			additions: stats.additions.unwrap_or(0),
+			deletions: stats.deletions.unwrap_or(0),
 		}
 	}
 }
 
-impl From<JsonValue> for CommitUserInfo {
-	fn from(value: JsonValue) -> Self {
-		let user_info = value.0;
-		CommitUserInfo {
-			name: user_info.get("name").and_then(|v| v.as_str()).unwrap().to_string(),
-			email: user_info.get("email").and_then(|v| v.as_str()).map(|s| s.to_string()),
-			avatar_url: user_info.get("avatar_url").and_then(|v| v.as_str()).unwrap().to_string(),
-			date: user_info
-				.get("date")
-				.and_then(|v| v.as_str())
-				.unwrap()
-				.to_string()
-				.parse()
-				.unwrap(),
+impl From<Org> for OrgInfo {
+	fn from(org: Org) -> Self {
+		OrgInfo {
+			id: org.id,
+			login: org.login,
+			name: org.name,
+			email: org.email,
+			avatar_url: org.avatar_url,
+			description: org.description,
+			follow_count: org.followers,
+			raw: None,
 		}
 	}
 }
 
-impl From<JsonValue> for StatsInfo {
-	fn from(value: JsonValue) -> Self {
-		let stats_info = value.0;
-		StatsInfo {
-			total: stats_info.get("total").and_then(|v| v.as_u64()).unwrap_or(0),
-			additions: stats_info.get("additions").and_then(|v| v.as_u64()).unwrap_or(0),
-			deletions: stats_info.get("deletions").and_then(|v| v.as_u64()).unwrap_or(0),
+impl From<ErrorBody> for ApiErrorBody {
+	fn from(body: ErrorBody) -> Self {
+		ApiErrorBody {
+			code: body.error_code_name.or(body.error_code.map(|code| code.to_string())),
+			message: body.error_message.or(body.message),
+			documentation_url: None,
 		}
 	}
 }
 
-impl From<JsonValue> for OrgInfo {
-	fn from(json_value: JsonValue) -> Self {
-		let org_info = json_value.0;
-		OrgInfo {
-			id: org_info.get("id").and_then(|v| v.as_u64()).unwrap(),
-			login: org_info.get("login").and_then(|v| v.as_str()).unwrap().to_string(),
-			name: org_info.get("name").and_then(|v| v.as_str()).map(|s| s.to_string()),
-			email: org_info.get("email").and_then(|v| v.as_str()).map(|s| s.to_string()),
-			avatar_url: org_info.get("avatar_url").and_then(|v| v.as_str()).unwrap().to_string(),
-			description: org_info
-				.get("description")
-				.and_then(|v| v.as_str())
-				.map(|s| s.to_string()),
-			follow_count: org_info.get("followers").and_then(|v| v.as_u64()).unwrap(),
+impl From<Branch> for BranchInfo {
+	fn from(branch: Branch) -> Self {
+		BranchInfo {
+			name: branch.name,
+			sha: branch.commit.sha,
+			protected: branch.protected.unwrap_or(false),
+			raw: None,
+		}
+	}
+}
+
+impl From<Tag> for TagInfo {
+	fn from(tag: Tag) -> Self {
+		let annotated = tag.tagger.is_some();
+		TagInfo {
+			name: tag.name,
+			sha: tag.commit.sha,
+			tagger: tag.tagger.map(|tagger| TaggerInfo {
+				name: tagger.name,
+				email: tagger.email,
+				date: tagger.date,
+			}),
+			message: tag.message.filter(|_| annotated),
+			annotated,
+			raw: None,
+		}
+	}
+}
+
+impl From<Release> for ReleaseInfo {
+	fn from(release: Release) -> Self {
+		ReleaseInfo {
+			id: release.tag_name.clone(),
+			tag_name: release.tag_name,
+			target_commitish: release.target_commitish,
+			name: release.name,
+			body: release.body,
+			draft: false,
+			prerelease: release.prerelease,
+			author: release.author.map(|author| author.login),
+			created_at: release.created_at,
+			published_at: release.created_at,
+			assets: release
+				.assets
+				.into_iter()
+				.filter(|asset| asset.asset_type.as_deref() != Some("source"))
+				.map(Into::into)
+				.collect(),
+			raw: None,
+		}
+	}
+}
+
+impl From<Asset> for ReleaseAsset {
+	fn from(asset: Asset) -> Self {
+		ReleaseAsset {
+			id: asset.name.clone(),
+			name: asset.name,
+			size: None,
+			content_type: None,
+			download_url: asset.browser_download_url,
+			download_count: None,
+		}
+	}
+}
+
+impl From<Issue> for IssueInfo {
+	fn from(issue: Issue) -> Self {
+		let closed = matches!(issue.state.as_str(), "closed" | "close");
+		IssueInfo {
+			number: issue.number,
+			title: issue.title,
+			body: issue.body,
+			state: if closed { IssueState::Closed } else { IssueState::Open },
+			labels: issue.labels.into_iter().map(|label| label.name).collect(),
+			assignees: issue.assignee.into_iter().map(|assignee| assignee.login).collect(),
+			milestone: issue.milestone.map(|milestone| milestone.title),
+			author: issue.user.map(|user| user.login),
+			pull_request: false,
+			created_at: issue.created_at,
+			updated_at: issue.updated_at,
+			closed_at: issue.finished_at,
+			raw: None,
 		}
 	}
 }
 
-impl From<JsonValue> for CollaboratorResult {
-	fn from(json_value: JsonValue) -> Self {
-		let collaborator = json_value.0;
-		CollaboratorResult {
-			login: collaborator.get("login").and_then(|v| v.as_str()).unwrap().to_string(),
-			avatar_url: collaborator
-				.get("avatar_url")
-				.and_then(|v| v.as_str())
-				.unwrap()
-				.to_string(),
+impl From<Comment> for CommentInfo {
+	fn from(comment: Comment) -> Self {
+		CommentInfo {
+			id: comment.id.to_string(),
+			body: comment.body,
+			author: comment.user.map(|user| user.login),
+			path: comment.path,
+			// 平台只返回评论在 diff 中的位置, 不返回行号
+			line: None,
+			created_at: comment.created_at,
+			updated_at: comment.updated_at,
+			raw: None,
 		}
 	}
 }
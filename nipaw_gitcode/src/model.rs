@@ -0,0 +1,254 @@
+//! GitCode API 的原始响应模型
+//!
+//! 字段与 GitCode OpenAPI v5 及网页端接口保持一致, 客户端返回的统一类型均由这些模型转换而来
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// 用户信息, 对应 `/user` 和 `/users/{username}`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct User {
+	pub id: u64,
+	pub login: String,
+	pub username: Option<String>,
+	pub name: Option<String>,
+	pub email: Option<String>,
+	pub avatar_url: String,
+	pub followers: u64,
+	pub following: u64,
+}
+
+/// 仓库、提交等数据中引用的账号
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Account {
+	pub login: String,
+	pub avatar_url: String,
+}
+
+/// 仓库信息, 对应 `/repos/{owner}/{repo}` 及各仓库列表接口
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Repo {
+	pub id: u64,
+	pub owner: Account,
+	pub name: String,
+	pub full_name: String,
+	pub description: Option<String>,
+	pub public: Option<bool>,
+	pub fork: Option<bool>,
+	pub forks_count: Option<u64>,
+	pub language: Option<String>,
+	pub stargazers_count: Option<u64>,
+	pub default_branch: String,
+	pub created_at: DateTime<Utc>,
+	pub updated_at: DateTime<Utc>,
+	pub pushed_at: DateTime<Utc>,
+}
+
+/// 组织信息, 对应网页端 API 的 `/orgs/{org}`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Org {
+	pub id: u64,
+	pub login: String,
+	pub name: Option<String>,
+	pub email: Option<String>,
+	pub avatar_url: String,
+	pub description: Option<String>,
+	pub followers: u64,
+}
+
+/// 提交信息, 对应 `/repos/{owner}/{repo}/commits/{ref}` 及提交列表接口
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Commit {
+	pub sha: String,
+	pub commit: CommitDetail,
+	/// 提交作者对应的 GitCode 账号, 作者邮箱未关联账号时为空
+	pub author: Option<Account>,
+	/// 提交者对应的 GitCode 账号, 提交者邮箱未关联账号时为空
+	pub committer: Option<Account>,
+	/// 提交统计, 仅获取单个提交时返回
+	pub stats: Option<CommitStats>,
+}
+
+/// Git 提交数据
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CommitDetail {
+	pub author: CommitSignature,
+	pub committer: CommitSignature,
+	pub message: String,
+}
+
+/// Git 提交签名
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CommitSignature {
+	pub name: String,
+	pub email: Option<String>,
+	pub date: DateTime<Utc>,
+}
+
+/// 提交统计
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CommitStats {
+	pub total: Option<u64>,
+	pub additions: Option<u64>,
+	pub deletions: Option<u64>,
+}
+
+/// 仓库协作者, 对应 `PUT /repos/{owner}/{repo}/collaborators/{username}`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Collaborator {
+	pub login: String,
+}
+
+/// 网页端用户资料, 对应 `/uc/api/v1/user/setting/profile`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct UserProfile {
+	pub avatar: String,
+}
+
+/// 网页端用户项目统计, 对应 `/api/v2/projects/profile/{username}`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ProjectProfile {
+	pub total: Option<u64>,
+}
+
+/// 网页端组织信息, 对应 `/api/v2/groups/{org}`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Group {
+	pub avatar: String,
+}
+
+/// 网页端贡献数据, 键为 `%Y-%m-%d` 格式的日期, 值为当日贡献次数
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Contributions(pub BTreeMap<String, u64>);
+
+/// 错误响应体, 对应所有非成功响应
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ErrorBody {
+	pub error_code: Option<i64>,
+	pub error_code_name: Option<String>,
+	pub error_message: Option<String>,
+	/// 部分接口仅返回该字段
+	pub message: Option<String>,
+}
+
+/// 分支信息, 对应 `/repos/{owner}/{repo}/branches` 和 `/repos/{owner}/{repo}/branches/{branch}`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Branch {
+	pub name: String,
+	pub commit: BranchCommit,
+	pub protected: Option<bool>,
+}
+
+/// 分支指向的提交
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BranchCommit {
+	#[serde(alias = "id")]
+	pub sha: String,
+}
+
+/// 标签, 对应 `/repos/{owner}/{repo}/tags`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Tag {
+	pub name: String,
+	pub message: Option<String>,
+	pub commit: TagCommit,
+	/// 标签创建者, 仅附注标签返回
+	pub tagger: Option<Tagger>,
+}
+
+/// 标签指向的提交
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TagCommit {
+	#[serde(alias = "id")]
+	pub sha: String,
+}
+
+/// 附注标签的创建者
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Tagger {
+	pub name: String,
+	pub email: Option<String>,
+	pub date: Option<DateTime<Utc>>,
+}
+
+/// 发布信息, 对应 `/repos/{owner}/{repo}/releases` 及单个发布接口
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Release {
+	pub tag_name: String,
+	pub target_commitish: Option<String>,
+	pub name: Option<String>,
+	pub body: Option<String>,
+	#[serde(default)]
+	pub prerelease: bool,
+	pub author: Option<ReleaseAuthor>,
+	pub created_at: Option<DateTime<Utc>>,
+	#[serde(default)]
+	pub assets: Vec<Asset>,
+}
+
+/// 发布者
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ReleaseAuthor {
+	pub login: String,
+}
+
+/// 发布附件, 平台自动生成的源码包 `type` 为 `source`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Asset {
+	pub name: String,
+	pub browser_download_url: String,
+	#[serde(rename = "type")]
+	pub asset_type: Option<String>,
+}
+
+/// 附件上传地址, 对应 `/repos/{owner}/{repo}/releases/{tag}/upload_url`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct UploadUrl {
+	pub url: String,
+	/// 上传时需要携带的请求头
+	#[serde(default)]
+	pub headers: BTreeMap<String, String>,
+}
+
+/// Issue 信息, 对应 `/repos/{owner}/{repo}/issues` 及单个 Issue 接口
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Issue {
+	pub number: String,
+	pub title: String,
+	pub body: Option<String>,
+	pub state: String,
+	#[serde(default)]
+	pub labels: Vec<Label>,
+	pub assignee: Option<Account>,
+	pub milestone: Option<Milestone>,
+	pub user: Option<Account>,
+	pub created_at: DateTime<Utc>,
+	pub updated_at: DateTime<Utc>,
+	/// 关闭时间
+	pub finished_at: Option<DateTime<Utc>>,
+}
+
+/// 标签
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Label {
+	pub name: String,
+}
+
+/// 里程碑
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Milestone {
+	pub title: String,
+}
+
+/// 评论, 对应 Issue 评论和 PR 评论接口
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Comment {
+	pub id: u64,
+	pub body: String,
+	pub user: Option<Account>,
+	/// 评论所在的文件路径, 仅 PR 代码评论返回
+	pub path: Option<String>,
+	pub created_at: DateTime<Utc>,
+	pub updated_at: Option<DateTime<Utc>>,
+}
@@ -1,41 +1,145 @@
 mod client;
 mod common;
 mod middleware;
+pub mod model;
 
-pub use nipaw_core::Client;
+pub use nipaw_core::{Client, ClientBuilder};
 
 use crate::{
-	client::{HTTP_CLIENT, PROXY_URL},
-	common::{Html, JsonValue},
+	client::build_http_client,
+	common::Html,
+	model::{
+		Account, Asset, Branch, Collaborator, Comment, Commit, Issue, Org, Release, Repo, Tag, User,
+	},
 };
 use async_trait::async_trait;
+use futures::{StreamExt, stream::BoxStream};
 use nipaw_core::{
-	Result,
+	FromConfig, Result,
+	config::ClientConfig,
+	decode,
 	error::Error,
-	option::{CommitListOptions, OrgRepoListOptions, ReposListOptions},
+	metrics::Operation,
+	option::{
+		BranchListOptions, CommentListOptions, CommitListOptions, IssueListOptions, IssueOptions,
+		IssueSort, IssueUpdateOptions, OrgRepoListOptions, OrgRepoType, ReleaseListOptions,
+		ReleaseOptions, ReleaseUpdateOptions, RepoSort, ReposListOptions, TagListOptions,
+	},
+	pagination::{NextPage, PagedClient, error_stream},
 	types::{
+		branch::BranchInfo,
 		collaborator::{CollaboratorPermission, CollaboratorResult},
+		comment::{CommentInfo, CommentTarget},
 		commit::CommitInfo,
+		issue::IssueInfo,
 		org::OrgInfo,
+		rate_limit::RateLimitInfo,
+		release::{AssetStream, AssetUpload, ReleaseAsset, ReleaseInfo},
 		repo::RepoInfo,
+		tag::TagInfo,
 		user::{ContributionResult, UserInfo},
 	},
 };
-use reqwest::header;
-use serde_json::Value;
+use reqwest::{StatusCode, header, multipart};
+use reqwest_middleware::{ClientWithMiddleware, RequestBuilder};
 use std::collections::HashMap;
 
+const PLATFORM: &str = "Gitee";
 const API_URL: &str = "https://gitee.com/api/v5";
 const BASE_URL: &str = "https://gitee.com";
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone)]
 pub struct GiteeClient {
-	pub token: Option<String>,
+	api_url: String,
+	base_url: String,
+	config: ClientConfig,
+	http: ClientWithMiddleware,
+}
+
+impl Default for GiteeClient {
+	fn default() -> Self {
+		Self::new()
+	}
 }
 
 impl GiteeClient {
 	pub fn new() -> Self {
-		Self::default()
+		Self::with_config(ClientConfig::default()).expect("default client config is valid")
+	}
+
+	/// 使用自定义配置创建客户端, 可用于对接私有化部署的 Gitee 实例
+	///
+	/// # 参数
+	///
+	/// * `config` - 客户端配置, 详见 [ClientConfig]
+	pub fn with_config(config: ClientConfig) -> Result<Self> {
+		config.validate()?;
+		let base_urls = &config.base_urls;
+		Ok(Self {
+			api_url: base_urls.api_url_or(API_URL)?,
+			base_url: base_urls.base_url_or(BASE_URL)?,
+			http: build_http_client(&config)?,
+			config,
+		})
+	}
+
+	/// 获取当前客户端配置
+	pub fn config(&self) -> &ClientConfig {
+		&self.config
+	}
+
+	/// 替换客户端配置, 会重新创建该实例的 HTTP 客户端
+	///
+	/// # 参数
+	///
+	/// * `config` - 客户端配置, 详见 [ClientConfig]
+	pub fn set_config(&mut self, config: ClientConfig) -> Result<()> {
+		*self = Self::with_config(config)?;
+		Ok(())
+	}
+
+	/// 修改 Issue, 更新和关闭共用
+	async fn patch_issue(
+		&self,
+		repo_path: (&str, &str),
+		number: &str,
+		body: serde_json::Value,
+		operation: &'static str,
+	) -> Result<IssueInfo> {
+		let url = format!("{}/repos/{}/issues/{}", self.api_url, repo_path.0, number);
+		let mut request = self.http.patch(url).with_extension(Operation(operation));
+		if let Some(token) = &self.config.token {
+			request = request.query(&[("access_token", token.as_str())]);
+		}
+		let resp = request
+			.header(header::CONTENT_TYPE, "application/json")
+			.body(body.to_string())
+			.send()
+			.await?;
+		decode::json_into::<Issue, _>(resp, self.config.include_raw).await
+	}
+}
+
+impl PagedClient for GiteeClient {
+	const PLATFORM: &'static str = PLATFORM;
+	const NEXT_PAGE: NextPage = NextPage::TotalPage;
+
+	fn page_request(&self, operation: &'static str, url: &str) -> RequestBuilder {
+		let mut request = self.http.get(url).with_extension(Operation(operation));
+		if let Some(token) = &self.config.token {
+			request = request.query(&[("access_token", token.as_str())]);
+		}
+		request
+	}
+
+	fn include_raw(&self) -> bool {
+		self.config.include_raw
+	}
+}
+
+impl FromConfig for GiteeClient {
+	fn from_config(config: ClientConfig) -> Result<Self> {
+		Self::with_config(config)
 	}
 }
 
@@ -45,58 +149,62 @@ impl Client for GiteeClient {
 		if token.is_empty() {
 			return Err(Error::TokenEmpty);
 		}
-		self.token = Some(token.to_string());
+		self.config.token = Some(token.to_string());
 		Ok(())
 	}
 
 	fn set_proxy(&mut self, proxy: &str) -> Result<()> {
-		PROXY_URL.set(proxy.to_string()).unwrap();
-		Ok(())
+		let mut config = self.config.clone();
+		config.proxy = Some(proxy.to_string());
+		self.set_config(config)
 	}
 
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
 	async fn get_user_info(&self) -> Result<UserInfo> {
-		if self.token.is_none() {
+		if self.config.token.is_none() {
 			return Err(Error::TokenEmpty);
 		}
-		let url = format!("{}/user", API_URL);
-		let request =
-			HTTP_CLIENT.get(url).query(&[("access_token", self.token.as_ref().unwrap().as_str())]);
+		let url = format!("{}/user", self.api_url);
+		let request = self
+			.http
+			.get(url)
+			.with_extension(Operation("get_user_info"))
+			.query(&[("access_token", self.config.token.as_ref().unwrap().as_str())]);
 
 		let resp = request.send().await?;
-		let user_info: JsonValue = resp.json().await?;
-		Ok(user_info.into())
+		decode::json_into::<User, _>(resp, self.config.include_raw).await
 	}
 
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
 	async fn get_user_info_with_name(&self, user_name: &str) -> Result<UserInfo> {
-		let url = format!("{}/users/{}", API_URL, user_name);
-		let mut request = HTTP_CLIENT.get(url);
-		if let Some(token) = &self.token {
+		let url = format!("{}/users/{}", self.api_url, user_name);
+		let mut request = self.http.get(url).with_extension(Operation("get_user_info_with_name"));
+		if let Some(token) = &self.config.token {
 			request = request.query(&[("access_token", token.as_str())]);
 		}
 		let resp = request.send().await?;
-		let user_info: JsonValue = resp.json().await?;
-		Ok(user_info.into())
+		decode::json_into::<User, _>(resp, self.config.include_raw).await
 	}
 
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
 	async fn get_user_avatar_url(&self, user_name: &str) -> Result<String> {
-		let url = format!("{}/users/{}/detail", BASE_URL, user_name);
-		let request = HTTP_CLIENT.get(url).header("Referer", BASE_URL);
+		let url = format!("{}/users/{}", self.api_url, user_name);
+		let mut request = self.http.get(url).with_extension(Operation("get_user_avatar_url"));
+		if let Some(token) = &self.config.token {
+			request = request.query(&[("access_token", token.as_str())]);
+		}
 		let resp = request.send().await?;
-		let user_info: JsonValue = resp.json().await?;
-		let avatar_url = user_info
-			.0
-			.get("data")
-			.and_then(|data| data.get("avatar_url"))
-			.and_then(|v| v.as_str())
-			.unwrap()
-			.to_string();
-		Ok(avatar_url)
+		let account: Account = decode::json(resp).await?;
+		Ok(account.avatar_url)
 	}
 
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
 	async fn get_user_contribution(&self, user_name: &str) -> Result<ContributionResult> {
-		let url = format!("{}/{}", BASE_URL, user_name);
-		let request = HTTP_CLIENT
+		let url = format!("{}/{}", self.base_url, user_name);
+		let request = self
+			.http
 			.get(url)
+			.with_extension(Operation("get_user_contribution"))
 			.header("X-Requested-With", "XMLHttpRequest")
 			.header("Accept", "application/javascript");
 		let resp = request.send().await?;
@@ -104,110 +212,138 @@ impl Client for GiteeClient {
 		Ok(html.into())
 	}
 
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
 	async fn get_org_info(&self, org_name: &str) -> Result<OrgInfo> {
-		let url = format!("{}/orgs/{}", BASE_URL, org_name);
-		let mut request = HTTP_CLIENT.get(url);
-		if let Some(token) = &self.token {
+		let url = format!("{}/orgs/{}", self.api_url, org_name);
+		let mut request = self.http.get(url).with_extension(Operation("get_org_info"));
+		if let Some(token) = &self.config.token {
 			request = request.query(&[("access_token", token.as_str())]);
 		}
 		let resp = request.send().await?;
-		let org_info: JsonValue = resp.json().await?;
-		Ok(org_info.into())
+		decode::json_into::<Org, _>(resp, self.config.include_raw).await
 	}
 
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
 	async fn get_org_repos(
 		&self,
 		org_name: &str,
-		options: Option<OrgRepoListOptions>,
+		option: Option<OrgRepoListOptions>,
 	) -> Result<Vec<RepoInfo>> {
-		let url = format!("{}/orgs/{}/repos", API_URL, org_name);
-		let mut request = HTTP_CLIENT.get(url);
-		let mut params = HashMap::new();
-		if let Some(token) = &self.token {
-			request = request.query(&[("access_token", token.as_str())]);
-		}
-		if let Some(option) = options {
-			let per_page = option.per_page.unwrap_or_default().min(100);
-			params.insert("per_page", per_page.to_string());
-			let page = option.page.unwrap_or_default();
-			params.insert("page", page.to_string());
+		let url = format!("{}/orgs/{}/repos", self.api_url, org_name);
+		let option = option.unwrap_or_default();
+		let params = org_repos_params(&option)?;
+		Ok(self.get_page::<Repo, _>("get_org_repos", &url, &params, option.timeout).await?.items)
+	}
+
+	fn get_org_repos_stream<'a>(
+		&'a self,
+		org_name: &'a str,
+		option: Option<OrgRepoListOptions>,
+		limit: Option<usize>,
+	) -> BoxStream<'a, Result<RepoInfo>> {
+		let url = format!("{}/orgs/{}/repos", self.api_url, org_name);
+		let option = option.unwrap_or_default();
+		match org_repos_params(&option) {
+			Ok(params) => self.paginate::<Repo, _>(
+				"get_org_repos_stream",
+				url,
+				params,
+				option.page(),
+				limit,
+				option.timeout,
+			),
+			Err(err) => error_stream(err),
 		}
-		let resp = request.send().await?;
-		let repo_list: Vec<JsonValue> = resp.json().await?;
-		Ok(repo_list.into_iter().map(|v| v.into()).collect())
 	}
 
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
 	async fn get_org_avatar_url(&self, org_name: &str) -> Result<String> {
-		let url = format!("{}/{}", BASE_URL, org_name);
-		let request = HTTP_CLIENT.get(url);
+		let url = format!("{}/orgs/{}", self.api_url, org_name);
+		let mut request = self.http.get(url).with_extension(Operation("get_org_avatar_url"));
+		if let Some(token) = &self.config.token {
+			request = request.query(&[("access_token", token.as_str())]);
+		}
 		let resp = request.send().await?;
-		let org_html: String = resp.text().await?;
-
-		let document = scraper::Html::parse_document(&org_html);
-		let selector = scraper::Selector::parse("img.avatar.current-group-avatar").unwrap();
-
-		let element = document.select(&selector).next().unwrap();
-		let src = element.value().attr("src").unwrap();
-		let avatar_url = src.split('!').next().unwrap_or(src).to_string();
-		Ok(avatar_url)
+		let org: Org = decode::json(resp).await?;
+		Ok(org.avatar_url)
 	}
 
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
 	async fn get_repo_info(&self, repo_path: (&str, &str)) -> Result<RepoInfo> {
-		let url = format!("{}/repos/{}/{}", API_URL, repo_path.0, repo_path.1);
-		let mut request = HTTP_CLIENT.get(url);
-		if let Some(token) = &self.token {
+		let url = format!("{}/repos/{}/{}", self.api_url, repo_path.0, repo_path.1);
+		let mut request = self.http.get(url).with_extension(Operation("get_repo_info"));
+		if let Some(token) = &self.config.token {
 			request = request.query(&[("access_token", token.as_str())]);
 		}
 		let resp = request.send().await?;
-		let repo_info: JsonValue = resp.json().await?;
-		Ok(repo_info.into())
+		decode::json_into::<Repo, _>(resp, self.config.include_raw).await
 	}
 
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
 	async fn get_user_repos(&self, option: Option<ReposListOptions>) -> Result<Vec<RepoInfo>> {
-		let url = format!("{}/user/repos", API_URL);
-		let request = HTTP_CLIENT.get(url);
-		let mut params: HashMap<&str, String> = HashMap::new();
-		if let Some(token) = &self.token {
-			params.insert("access_token", token.to_owned());
-		}
-
-		params.insert("sort", "updated".to_string());
+		let url = format!("{}/user/repos", self.api_url);
+		let option = option.unwrap_or_default();
+		let params = repos_params(&option, true, RepoSort::Updated)?;
+		Ok(self.get_page::<Repo, _>("get_user_repos", &url, &params, option.timeout).await?.items)
+	}
 
-		if let Some(option) = option {
-			let per_page = option.per_page.unwrap_or_default().min(100);
-			params.insert("per_page", per_page.to_string());
-			let page = option.page.unwrap_or_default();
-			params.insert("page", page.to_string());
+	fn get_user_repos_stream(
+		&self,
+		option: Option<ReposListOptions>,
+		limit: Option<usize>,
+	) -> BoxStream<'_, Result<RepoInfo>> {
+		let url = format!("{}/user/repos", self.api_url);
+		let option = option.unwrap_or_default();
+		match repos_params(&option, true, RepoSort::Updated) {
+			Ok(params) => self.paginate::<Repo, _>(
+				"get_user_repos_stream",
+				url,
+				params,
+				option.page(),
+				limit,
+				option.timeout,
+			),
+			Err(err) => error_stream(err),
 		}
-		let resp = request.query(&params).send().await?;
-		let repo_infos: Vec<JsonValue> = resp.json().await?;
-		Ok(repo_infos.into_iter().map(|v| v.into()).collect())
 	}
 
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
 	async fn get_user_repos_with_name(
 		&self,
 		user_name: &str,
 		option: Option<ReposListOptions>,
 	) -> Result<Vec<RepoInfo>> {
-		let url = format!("{}/users/{}/repos", API_URL, user_name);
-		let request = HTTP_CLIENT.get(url);
-		let mut params: HashMap<&str, String> = HashMap::new();
-		if let Some(token) = &self.token {
-			params.insert("access_token", token.to_owned());
-		};
-		params.insert("sort", "pushed".to_string());
+		let url = format!("{}/users/{}/repos", self.api_url, user_name);
+		let option = option.unwrap_or_default();
+		let params = repos_params(&option, false, RepoSort::Pushed)?;
+		Ok(self
+			.get_page::<Repo, _>("get_user_repos_with_name", &url, &params, option.timeout)
+			.await?
+			.items)
+	}
 
-		if let Some(option) = option {
-			let per_page = option.per_page.unwrap_or_default().min(100);
-			params.insert("per_page", per_page.to_string());
-			let page = option.page.unwrap_or_default();
-			params.insert("page", page.to_string());
+	fn get_user_repos_with_name_stream<'a>(
+		&'a self,
+		user_name: &'a str,
+		option: Option<ReposListOptions>,
+		limit: Option<usize>,
+	) -> BoxStream<'a, Result<RepoInfo>> {
+		let url = format!("{}/users/{}/repos", self.api_url, user_name);
+		let option = option.unwrap_or_default();
+		match repos_params(&option, false, RepoSort::Pushed) {
+			Ok(params) => self.paginate::<Repo, _>(
+				"get_user_repos_with_name_stream",
+				url,
+				params,
+				option.page(),
+				limit,
+				option.timeout,
+			),
+			Err(err) => error_stream(err),
 		}
-		let resp = request.query(&params).send().await?;
-		let repo_infos: Vec<JsonValue> = resp.json().await?;
-		Ok(repo_infos.into_iter().map(|v| v.into()).collect())
 	}
 
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
 	async fn get_commit_info(
 		&self,
 		repo_path: (&str, &str),
@@ -215,88 +351,53 @@ impl Client for GiteeClient {
 	) -> Result<CommitInfo> {
 		let url = format!(
 			"{}/repos/{}/{}/commits/{}",
-			API_URL,
+			self.api_url,
 			repo_path.0,
 			repo_path.1,
 			sha.unwrap_or("HEAD")
 		);
-		let mut request = HTTP_CLIENT.get(url);
-		if let Some(token) = &self.token {
+		let mut request = self.http.get(url).with_extension(Operation("get_commit_info"));
+		if let Some(token) = &self.config.token {
 			request = request.query(&[("access_token", token.as_str())]);
 		}
 		let resp = request.send().await?;
-		let mut commit_info: JsonValue = resp.json().await?;
-		let author_avatar_url = commit_info
-			.0
-			.get("author")
-			.and_then(|v| v.get("avatar_url"))
-			.and_then(|v| v.as_str())
-			.unwrap()
-			.to_string();
-		let committer_avatar_url = commit_info
-			.0
-			.get("committer")
-			.and_then(|v| v.get("avatar_url"))
-			.and_then(|v| v.as_str())
-			.unwrap()
-			.to_string();
-		if let Some(author_obj) = commit_info
-			.0
-			.get_mut("commit")
-			.and_then(|commit| commit.as_object_mut())
-			.and_then(|commit_obj| commit_obj.get_mut("author"))
-			.and_then(|author| author.as_object_mut())
-		{
-			author_obj.insert("avatar_url".to_string(), Value::String(author_avatar_url));
-		}
-
-		if let Some(committer_obj) = commit_info
-			.0
-			.get_mut("commit")
-			.and_then(|commit| commit.as_object_mut())
-			.and_then(|commit_obj| commit_obj.get_mut("committer"))
-			.and_then(|committer| committer.as_object_mut())
-		{
-			committer_obj.insert("avatar_url".to_string(), Value::String(committer_avatar_url));
-		}
-		Ok(commit_info.into())
+		decode::json_into::<Commit, _>(resp, self.config.include_raw).await
 	}
 
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
 	async fn get_commit_infos(
 		&self,
 		repo_path: (&str, &str),
 		option: Option<CommitListOptions>,
 	) -> Result<Vec<CommitInfo>> {
-		let url = format!("{}/repos/{}/{}/commits", API_URL, repo_path.0, repo_path.1);
-		let request = HTTP_CLIENT.get(url);
-		let mut params: HashMap<&str, String> = HashMap::new();
-		if let Some(token) = &self.token {
-			params.insert("access_token", token.to_owned());
-		}
-
-		if let Some(option) = option {
-			let per_page = option.per_page.unwrap_or_default().min(100);
-			params.insert("per_page", per_page.to_string());
-			let page = option.page.unwrap_or_default();
-			params.insert("page", page.to_string());
-			if let Some(sha) = option.sha {
-				params.insert("sha", sha.to_string());
-			}
-			if let Some(author) = option.author {
-				params.insert("author", author.to_string());
-			}
-			if let Some(since) = option.since {
-				params.insert("since", since.to_rfc3339());
-			}
-			if let Some(until) = option.until {
-				params.insert("until", until.to_rfc3339());
-			}
-		}
-		let resp = request.query(&params).send().await?;
-		let commit_infos: Vec<JsonValue> = resp.json().await?;
-		Ok(commit_infos.into_iter().map(|v| v.into()).collect())
+		let url = format!("{}/repos/{}/{}/commits", self.api_url, repo_path.0, repo_path.1);
+		let option = option.unwrap_or_default();
+		let params = commits_params(&option);
+		Ok(self
+			.get_page::<Commit, _>("get_commit_infos", &url, &params, option.timeout)
+			.await?
+			.items)
+	}
+
+	fn get_commit_infos_stream<'a>(
+		&'a self,
+		repo_path: (&'a str, &'a str),
+		option: Option<CommitListOptions>,
+		limit: Option<usize>,
+	) -> BoxStream<'a, Result<CommitInfo>> {
+		let url = format!("{}/repos/{}/{}/commits", self.api_url, repo_path.0, repo_path.1);
+		let option = option.unwrap_or_default();
+		self.paginate::<Commit, _>(
+			"get_commit_infos_stream",
+			url,
+			commits_params(&option),
+			option.page(),
+			limit,
+			option.timeout,
+		)
 	}
 
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
 	async fn add_repo_collaborator(
 		&self,
 		repo_path: (&str, &str),
@@ -305,9 +406,9 @@ impl Client for GiteeClient {
 	) -> Result<CollaboratorResult> {
 		let url = format!(
 			"{}/repos/{}/{}/collaborators/{}",
-			API_URL, repo_path.0, repo_path.1, user_name
+			self.api_url, repo_path.0, repo_path.1, user_name
 		);
-		let request = HTTP_CLIENT.put(url);
+		let request = self.http.put(url).with_extension(Operation("add_repo_collaborator"));
 
 		let permission = match permission {
 			Some(permission) => match permission {
@@ -318,7 +419,7 @@ impl Client for GiteeClient {
 			None => "pull".to_string(),
 		};
 
-		let body = if let Some(token) = &self.token {
+		let body = if let Some(token) = &self.config.token {
 			serde_json::json!({
 				"access_token": token.to_string(),
 				"permission": permission,
@@ -334,7 +435,894 @@ impl Client for GiteeClient {
 			.body(body.to_string())
 			.send()
 			.await?;
-		let collaborator: JsonValue = resp.json().await?;
+		// 用户已是协作者时平台返回 204, 没有响应体
+		if resp.status() == StatusCode::NO_CONTENT {
+			return Ok(CollaboratorResult {
+				login: user_name.to_string(),
+				avatar_url: self.get_user_avatar_url(user_name).await?,
+			});
+		}
+		let collaborator: Collaborator = decode::json(resp).await?;
 		Ok(collaborator.into())
 	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn get_branches(
+		&self,
+		repo_path: (&str, &str),
+		option: Option<BranchListOptions>,
+	) -> Result<Vec<BranchInfo>> {
+		let url = format!("{}/repos/{}/{}/branches", self.api_url, repo_path.0, repo_path.1);
+		let option = option.unwrap_or_default();
+		let params = branches_params(&option);
+		Ok(self.get_page::<Branch, _>("get_branches", &url, &params, option.timeout).await?.items)
+	}
+
+	fn get_branches_stream<'a>(
+		&'a self,
+		repo_path: (&'a str, &'a str),
+		option: Option<BranchListOptions>,
+		limit: Option<usize>,
+	) -> BoxStream<'a, Result<BranchInfo>> {
+		let url = format!("{}/repos/{}/{}/branches", self.api_url, repo_path.0, repo_path.1);
+		let option = option.unwrap_or_default();
+		let params = branches_params(&option);
+		self.paginate::<Branch, _>(
+			"get_branches_stream",
+			url,
+			params,
+			option.page(),
+			limit,
+			option.timeout,
+		)
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn get_branch(&self, repo_path: (&str, &str), branch: &str) -> Result<BranchInfo> {
+		let url =
+			format!("{}/repos/{}/{}/branches/{}", self.api_url, repo_path.0, repo_path.1, branch);
+		let mut request = self.http.get(url).with_extension(Operation("get_branch"));
+		if let Some(token) = &self.config.token {
+			request = request.query(&[("access_token", token.as_str())]);
+		}
+		let resp = request.send().await?;
+		decode::json_into::<Branch, _>(resp, self.config.include_raw).await
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn create_branch(
+		&self,
+		repo_path: (&str, &str),
+		branch: &str,
+		from: &str,
+	) -> Result<BranchInfo> {
+		let url = format!("{}/repos/{}/{}/branches", self.api_url, repo_path.0, repo_path.1);
+		let mut request = self.http.post(url).with_extension(Operation("create_branch"));
+		if let Some(token) = &self.config.token {
+			request = request.query(&[("access_token", token.as_str())]);
+		}
+		let body = serde_json::json!({
+			"refs": from,
+			"branch_name": branch,
+		});
+		let resp = request
+			.header(header::CONTENT_TYPE, "application/json")
+			.body(body.to_string())
+			.send()
+			.await?;
+		decode::json_into::<Branch, _>(resp, self.config.include_raw).await
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn delete_branch(&self, _repo_path: (&str, &str), _branch: &str) -> Result<()> {
+		Err(unsupported("delete_branch".to_string()))
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn get_tags(
+		&self,
+		repo_path: (&str, &str),
+		option: Option<TagListOptions>,
+	) -> Result<Vec<TagInfo>> {
+		let url = format!("{}/repos/{}/{}/tags", self.api_url, repo_path.0, repo_path.1);
+		let option = option.unwrap_or_default();
+		let params = tags_params(&option);
+		Ok(self.get_page::<Tag, _>("get_tags", &url, &params, option.timeout).await?.items)
+	}
+
+	fn get_tags_stream<'a>(
+		&'a self,
+		repo_path: (&'a str, &'a str),
+		option: Option<TagListOptions>,
+		limit: Option<usize>,
+	) -> BoxStream<'a, Result<TagInfo>> {
+		let url = format!("{}/repos/{}/{}/tags", self.api_url, repo_path.0, repo_path.1);
+		let option = option.unwrap_or_default();
+		self.paginate::<Tag, _>(
+			"get_tags_stream",
+			url,
+			tags_params(&option),
+			option.page(),
+			limit,
+			option.timeout,
+		)
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn get_tag(&self, repo_path: (&str, &str), tag: &str) -> Result<TagInfo> {
+		// 平台没有获取单个标签的接口, 按每页最大数量遍历标签列表查找
+		let option = TagListOptions { per_page: Some(100), ..Default::default() };
+		let mut tags = self.get_tags_stream(repo_path, Some(option), None);
+		while let Some(tag_info) = tags.next().await {
+			let tag_info = tag_info?;
+			if tag_info.name == tag {
+				return Ok(tag_info);
+			}
+		}
+		Err(Error::NotFound)
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn create_tag(
+		&self,
+		repo_path: (&str, &str),
+		tag: &str,
+		target: &str,
+		message: Option<&str>,
+	) -> Result<TagInfo> {
+		let url = format!("{}/repos/{}/{}/tags", self.api_url, repo_path.0, repo_path.1);
+		let mut request = self.http.post(url).with_extension(Operation("create_tag"));
+		if let Some(token) = &self.config.token {
+			request = request.query(&[("access_token", token.as_str())]);
+		}
+		let mut body = serde_json::json!({
+			"refs": target,
+			"tag_name": tag,
+		});
+		if let Some(message) = message {
+			body["tag_message"] = message.into();
+		}
+		let resp = request
+			.header(header::CONTENT_TYPE, "application/json")
+			.body(body.to_string())
+			.send()
+			.await?;
+		decode::json_into::<Tag, _>(resp, self.config.include_raw).await
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn delete_tag(&self, _repo_path: (&str, &str), _tag: &str) -> Result<()> {
+		Err(unsupported("delete_tag".to_string()))
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn get_releases(
+		&self,
+		repo_path: (&str, &str),
+		option: Option<ReleaseListOptions>,
+	) -> Result<Vec<ReleaseInfo>> {
+		let url = format!("{}/repos/{}/{}/releases", self.api_url, repo_path.0, repo_path.1);
+		let option = option.unwrap_or_default();
+		let params = releases_params(&option);
+		Ok(self.get_page::<Release, _>("get_releases", &url, &params, option.timeout).await?.items)
+	}
+
+	fn get_releases_stream<'a>(
+		&'a self,
+		repo_path: (&'a str, &'a str),
+		option: Option<ReleaseListOptions>,
+		limit: Option<usize>,
+	) -> BoxStream<'a, Result<ReleaseInfo>> {
+		let url = format!("{}/repos/{}/{}/releases", self.api_url, repo_path.0, repo_path.1);
+		let option = option.unwrap_or_default();
+		self.paginate::<Release, _>(
+			"get_releases_stream",
+			url,
+			releases_params(&option),
+			option.page(),
+			limit,
+			option.timeout,
+		)
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn get_latest_release(&self, repo_path: (&str, &str)) -> Result<ReleaseInfo> {
+		let url = format!("{}/repos/{}/{}/releases/latest", self.api_url, repo_path.0, repo_path.1);
+		let mut request = self.http.get(url).with_extension(Operation("get_latest_release"));
+		if let Some(token) = &self.config.token {
+			request = request.query(&[("access_token", token.as_str())]);
+		}
+		let resp = request.send().await?;
+		decode::json_into::<Release, _>(resp, self.config.include_raw).await
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn get_release_by_tag(&self, repo_path: (&str, &str), tag: &str) -> Result<ReleaseInfo> {
+		let url =
+			format!("{}/repos/{}/{}/releases/tags/{}", self.api_url, repo_path.0, repo_path.1, tag);
+		let mut request = self.http.get(url).with_extension(Operation("get_release_by_tag"));
+		if let Some(token) = &self.config.token {
+			request = request.query(&[("access_token", token.as_str())]);
+		}
+		let resp = request.send().await?;
+		decode::json_into::<Release, _>(resp, self.config.include_raw).await
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn create_release(
+		&self,
+		repo_path: (&str, &str),
+		option: ReleaseOptions,
+	) -> Result<ReleaseInfo> {
+		let option = ReleaseUpdateOptions::from(option);
+		let mut body = release_body(&option, None)?;
+		// 平台要求提供目标分支, 未设置时使用仓库默认分支
+		if option.target_commitish.is_none() {
+			body["target_commitish"] = self.get_repo_info(repo_path).await?.default_branch.into();
+		}
+		let url = format!("{}/repos/{}/{}/releases", self.api_url, repo_path.0, repo_path.1);
+		let mut request = self.http.post(url).with_extension(Operation("create_release"));
+		if let Some(token) = &self.config.token {
+			request = request.query(&[("access_token", token.as_str())]);
+		}
+		let resp = request
+			.header(header::CONTENT_TYPE, "application/json")
+			.body(body.to_string())
+			.send()
+			.await?;
+		decode::json_into::<Release, _>(resp, self.config.include_raw).await
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn update_release(
+		&self,
+		repo_path: (&str, &str),
+		release_id: &str,
+		option: ReleaseUpdateOptions,
+	) -> Result<ReleaseInfo> {
+		if option.draft == Some(true) {
+			return Err(unsupported("draft".to_string()));
+		}
+		let url = format!(
+			"{}/repos/{}/{}/releases/{}",
+			self.api_url, repo_path.0, repo_path.1, release_id
+		);
+		// 平台要求提供标签、标题和说明, 未设置的字段沿用已有发布
+		let mut request = self.http.get(&url).with_extension(Operation("update_release"));
+		if let Some(token) = &self.config.token {
+			request = request.query(&[("access_token", token.as_str())]);
+		}
+		let resp = request.send().await?;
+		let current = decode::json_into::<Release, ReleaseInfo>(resp, false).await?;
+		let body = release_body(&option, Some(&current))?;
+		let mut request = self.http.patch(url).with_extension(Operation("update_release"));
+		if let Some(token) = &self.config.token {
+			request = request.query(&[("access_token", token.as_str())]);
+		}
+		let resp = request
+			.header(header::CONTENT_TYPE, "application/json")
+			.body(body.to_string())
+			.send()
+			.await?;
+		decode::json_into::<Release, _>(resp, self.config.include_raw).await
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn delete_release(&self, repo_path: (&str, &str), release_id: &str) -> Result<()> {
+		let url = format!(
+			"{}/repos/{}/{}/releases/{}",
+			self.api_url, repo_path.0, repo_path.1, release_id
+		);
+		let mut request = self.http.delete(url).with_extension(Operation("delete_release"));
+		if let Some(token) = &self.config.token {
+			request = request.query(&[("access_token", token.as_str())]);
+		}
+		request.send().await?;
+		Ok(())
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn upload_release_asset(
+		&self,
+		repo_path: (&str, &str),
+		release_id: &str,
+		asset: AssetUpload,
+	) -> Result<ReleaseAsset> {
+		let url = format!(
+			"{}/repos/{}/{}/releases/{}/attach_files",
+			self.api_url, repo_path.0, repo_path.1, release_id
+		);
+		let mut request = self.http.post(url).with_extension(Operation("upload_release_asset"));
+		if let Some(token) = &self.config.token {
+			request = request.query(&[("access_token", token.as_str())]);
+		}
+		let mime = asset.mime().to_string();
+		let part = multipart::Part::stream_with_length(asset.body, asset.size)
+			.file_name(asset.name)
+			.mime_str(&mime)?;
+		let resp = request.multipart(multipart::Form::new().part("file", part)).send().await?;
+		let asset: Asset = decode::json(resp).await?;
+		Ok(asset.into())
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn download_release_asset(
+		&self,
+		repo_path: (&str, &str),
+		release_id: &str,
+		asset_id: &str,
+	) -> Result<AssetStream> {
+		let url = format!(
+			"{}/repos/{}/{}/releases/{}/attach_files/{}/download",
+			self.api_url, repo_path.0, repo_path.1, release_id, asset_id
+		);
+		let mut request = self.http.get(url).with_extension(Operation("download_release_asset"));
+		if let Some(token) = &self.config.token {
+			request = request.query(&[("access_token", token.as_str())]);
+		}
+		let resp = request.send().await?;
+		Ok(decode::bytes_stream(resp))
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn delete_release_asset(
+		&self,
+		repo_path: (&str, &str),
+		release_id: &str,
+		asset_id: &str,
+	) -> Result<()> {
+		let url = format!(
+			"{}/repos/{}/{}/releases/{}/attach_files/{}",
+			self.api_url, repo_path.0, repo_path.1, release_id, asset_id
+		);
+		let mut request = self.http.delete(url).with_extension(Operation("delete_release_asset"));
+		if let Some(token) = &self.config.token {
+			request = request.query(&[("access_token", token.as_str())]);
+		}
+		request.send().await?;
+		Ok(())
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn get_issues(
+		&self,
+		repo_path: (&str, &str),
+		option: Option<IssueListOptions>,
+	) -> Result<Vec<IssueInfo>> {
+		let url = format!("{}/repos/{}/{}/issues", self.api_url, repo_path.0, repo_path.1);
+		let option = option.unwrap_or_default();
+		let params = issues_params(&option)?;
+		Ok(self.get_page::<Issue, _>("get_issues", &url, &params, option.timeout).await?.items)
+	}
+
+	fn get_issues_stream<'a>(
+		&'a self,
+		repo_path: (&'a str, &'a str),
+		option: Option<IssueListOptions>,
+		limit: Option<usize>,
+	) -> BoxStream<'a, Result<IssueInfo>> {
+		let url = format!("{}/repos/{}/{}/issues", self.api_url, repo_path.0, repo_path.1);
+		let option = option.unwrap_or_default();
+		match issues_params(&option) {
+			Ok(params) => self.paginate::<Issue, _>(
+				"get_issues_stream",
+				url,
+				params,
+				option.page(),
+				limit,
+				option.timeout,
+			),
+			Err(err) => error_stream(err),
+		}
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn get_issue(&self, repo_path: (&str, &str), number: &str) -> Result<IssueInfo> {
+		let url =
+			format!("{}/repos/{}/{}/issues/{}", self.api_url, repo_path.0, repo_path.1, number);
+		let mut request = self.http.get(url).with_extension(Operation("get_issue"));
+		if let Some(token) = &self.config.token {
+			request = request.query(&[("access_token", token.as_str())]);
+		}
+		let resp = request.send().await?;
+		decode::json_into::<Issue, _>(resp, self.config.include_raw).await
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn create_issue(
+		&self,
+		repo_path: (&str, &str),
+		option: IssueOptions,
+	) -> Result<IssueInfo> {
+		let body = issue_body(repo_path.1, &option.into())?;
+		// 创建接口位于仓库所有者路径下, 仓库名称放在请求体中
+		let url = format!("{}/repos/{}/issues", self.api_url, repo_path.0);
+		let mut request = self.http.post(url).with_extension(Operation("create_issue"));
+		if let Some(token) = &self.config.token {
+			request = request.query(&[("access_token", token.as_str())]);
+		}
+		let resp = request
+			.header(header::CONTENT_TYPE, "application/json")
+			.body(body.to_string())
+			.send()
+			.await?;
+		decode::json_into::<Issue, _>(resp, self.config.include_raw).await
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn update_issue(
+		&self,
+		repo_path: (&str, &str),
+		number: &str,
+		option: IssueUpdateOptions,
+	) -> Result<IssueInfo> {
+		let body = issue_body(repo_path.1, &option)?;
+		self.patch_issue(repo_path, number, body, "update_issue").await
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn close_issue(&self, repo_path: (&str, &str), number: &str) -> Result<IssueInfo> {
+		let body = serde_json::json!({ "repo": repo_path.1, "state": "closed" });
+		self.patch_issue(repo_path, number, body, "close_issue").await
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn lock_issue(&self, _repo_path: (&str, &str), _number: &str) -> Result<()> {
+		Err(unsupported("lock_issue".to_string()))
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn unlock_issue(&self, _repo_path: (&str, &str), _number: &str) -> Result<()> {
+		Err(unsupported("unlock_issue".to_string()))
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn get_comments(
+		&self,
+		repo_path: (&str, &str),
+		target: CommentTarget,
+		number: &str,
+		option: Option<CommentListOptions>,
+	) -> Result<Vec<CommentInfo>> {
+		let url = format!(
+			"{}/repos/{}/{}/{}/{}/comments",
+			self.api_url,
+			repo_path.0,
+			repo_path.1,
+			comment_path(target),
+			number
+		);
+		let option = option.unwrap_or_default();
+		let params = comments_params(&option, target, "pr_comment");
+		Ok(self.get_page::<Comment, _>("get_comments", &url, &params, option.timeout).await?.items)
+	}
+
+	fn get_comments_stream<'a>(
+		&'a self,
+		repo_path: (&'a str, &'a str),
+		target: CommentTarget,
+		number: &'a str,
+		option: Option<CommentListOptions>,
+		limit: Option<usize>,
+	) -> BoxStream<'a, Result<CommentInfo>> {
+		let url = format!(
+			"{}/repos/{}/{}/{}/{}/comments",
+			self.api_url,
+			repo_path.0,
+			repo_path.1,
+			comment_path(target),
+			number
+		);
+		let option = option.unwrap_or_default();
+		self.paginate::<Comment, _>(
+			"get_comments_stream",
+			url,
+			comments_params(&option, target, "pr_comment"),
+			option.page(),
+			limit,
+			option.timeout,
+		)
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn get_review_comments(
+		&self,
+		repo_path: (&str, &str),
+		number: &str,
+		option: Option<CommentListOptions>,
+	) -> Result<Vec<CommentInfo>> {
+		let url = format!(
+			"{}/repos/{}/{}/pulls/{}/comments",
+			self.api_url, repo_path.0, repo_path.1, number
+		);
+		let option = option.unwrap_or_default();
+		let params = comments_params(&option, CommentTarget::PullRequest, "diff_comment");
+		Ok(self
+			.get_page::<Comment, _>("get_review_comments", &url, &params, option.timeout)
+			.await?
+			.items)
+	}
+
+	fn get_review_comments_stream<'a>(
+		&'a self,
+		repo_path: (&'a str, &'a str),
+		number: &'a str,
+		option: Option<CommentListOptions>,
+		limit: Option<usize>,
+	) -> BoxStream<'a, Result<CommentInfo>> {
+		let url = format!(
+			"{}/repos/{}/{}/pulls/{}/comments",
+			self.api_url, repo_path.0, repo_path.1, number
+		);
+		let option = option.unwrap_or_default();
+		self.paginate::<Comment, _>(
+			"get_review_comments_stream",
+			url,
+			comments_params(&option, CommentTarget::PullRequest, "diff_comment"),
+			option.page(),
+			limit,
+			option.timeout,
+		)
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn create_comment(
+		&self,
+		repo_path: (&str, &str),
+		target: CommentTarget,
+		number: &str,
+		body: &str,
+	) -> Result<CommentInfo> {
+		let url = format!(
+			"{}/repos/{}/{}/{}/{}/comments",
+			self.api_url,
+			repo_path.0,
+			repo_path.1,
+			comment_path(target),
+			number
+		);
+		let mut request = self.http.post(url).with_extension(Operation("create_comment"));
+		if let Some(token) = &self.config.token {
+			request = request.query(&[("access_token", token.as_str())]);
+		}
+		let resp = request
+			.header(header::CONTENT_TYPE, "application/json")
+			.body(serde_json::json!({ "body": body }).to_string())
+			.send()
+			.await?;
+		decode::json_into::<Comment, _>(resp, self.config.include_raw).await
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn update_comment(
+		&self,
+		repo_path: (&str, &str),
+		target: CommentTarget,
+		_number: &str,
+		comment_id: &str,
+		body: &str,
+	) -> Result<CommentInfo> {
+		let url = format!(
+			"{}/repos/{}/{}/{}/comments/{}",
+			self.api_url,
+			repo_path.0,
+			repo_path.1,
+			comment_path(target),
+			comment_id
+		);
+		let mut request = self.http.patch(url).with_extension(Operation("update_comment"));
+		if let Some(token) = &self.config.token {
+			request = request.query(&[("access_token", token.as_str())]);
+		}
+		let resp = request
+			.header(header::CONTENT_TYPE, "application/json")
+			.body(serde_json::json!({ "body": body }).to_string())
+			.send()
+			.await?;
+		decode::json_into::<Comment, _>(resp, self.config.include_raw).await
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn delete_comment(
+		&self,
+		repo_path: (&str, &str),
+		target: CommentTarget,
+		_number: &str,
+		comment_id: &str,
+	) -> Result<()> {
+		let url = format!(
+			"{}/repos/{}/{}/{}/comments/{}",
+			self.api_url,
+			repo_path.0,
+			repo_path.1,
+			comment_path(target),
+			comment_id
+		);
+		let mut request = self.http.delete(url).with_extension(Operation("delete_comment"));
+		if let Some(token) = &self.config.token {
+			request = request.query(&[("access_token", token.as_str())]);
+		}
+		request.send().await?;
+		Ok(())
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn get_rate_limit(&self) -> Result<RateLimitInfo> {
+		if self.config.token.is_none() {
+			return Err(Error::TokenEmpty);
+		}
+		let url = format!("{}/user", self.api_url);
+		let request = self
+			.http
+			.get(url)
+			.with_extension(Operation("get_rate_limit"))
+			.query(&[("access_token", self.config.token.as_ref().unwrap().as_str())]);
+		RateLimitInfo::from_result(request.send().await)
+	}
+}
+
+fn org_repos_params(option: &OrgRepoListOptions) -> Result<HashMap<&'static str, String>> {
+	let mut params = HashMap::new();
+	params.insert("per_page", option.per_page().to_string());
+	params.insert("page", option.page().to_string());
+	match option.repo_type {
+		None => {}
+		Some(repo_type @ (OrgRepoType::All | OrgRepoType::Public | OrgRepoType::Private)) => {
+			params.insert("type", repo_type.to_string());
+		}
+		Some(repo_type) => return Err(unsupported(format!("type={repo_type}"))),
+	}
+	if option.sort.is_some() {
+		return Err(unsupported("sort".to_string()));
+	}
+	if option.direction.is_some() {
+		return Err(unsupported("direction".to_string()));
+	}
+	Ok(params)
+}
+
+fn unsupported(option: String) -> Error {
+	Error::Unsupported { platform: PLATFORM, option }
+}
+
+fn repos_params(
+	option: &ReposListOptions,
+	authenticated: bool,
+	default_sort: RepoSort,
+) -> Result<HashMap<&'static str, String>> {
+	let mut params = HashMap::new();
+	params.insert("sort", option.sort.unwrap_or(default_sort).to_string());
+	params.insert("per_page", option.per_page().to_string());
+	params.insert("page", option.page().to_string());
+	if let Some(direction) = option.direction {
+		params.insert("direction", direction.to_string());
+	}
+	if let Some(visibility) = option.visibility {
+		if !authenticated {
+			return Err(unsupported("visibility".to_string()));
+		}
+		params.insert("visibility", visibility.to_string());
+	}
+	if let Some(affiliation) = option.affiliation {
+		if !authenticated {
+			return Err(unsupported("affiliation".to_string()));
+		}
+		params.insert("affiliation", affiliation.to_string());
+	}
+	if let Some(repo_type) = option.repo_type {
+		if option.visibility.is_some() || option.affiliation.is_some() {
+			return Err(unsupported("type with visibility or affiliation".to_string()));
+		}
+		params.insert("type", repo_type.to_string());
+	}
+	if let Some(search) = &option.search {
+		if !authenticated {
+			return Err(unsupported("search".to_string()));
+		}
+		params.insert("q", search.to_string());
+	}
+	Ok(params)
+}
+
+fn commits_params(option: &CommitListOptions) -> HashMap<&'static str, String> {
+	let mut params = HashMap::new();
+	params.insert("per_page", option.per_page().to_string());
+	params.insert("page", option.page().to_string());
+	if let Some(sha) = &option.sha {
+		params.insert("sha", sha.to_string());
+	}
+	if let Some(author) = &option.author {
+		params.insert("author", author.to_string());
+	}
+	if let Some(since) = option.since {
+		params.insert("since", since.to_rfc3339());
+	}
+	if let Some(until) = option.until {
+		params.insert("until", until.to_rfc3339());
+	}
+	params
+}
+
+fn branches_params(option: &BranchListOptions) -> HashMap<&'static str, String> {
+	let mut params = HashMap::new();
+	params.insert("per_page", option.per_page().to_string());
+	params.insert("page", option.page().to_string());
+	params
+}
+
+fn tags_params(option: &TagListOptions) -> HashMap<&'static str, String> {
+	let mut params = HashMap::new();
+	params.insert("per_page", option.per_page().to_string());
+	params.insert("page", option.page().to_string());
+	params
+}
+
+fn releases_params(option: &ReleaseListOptions) -> HashMap<&'static str, String> {
+	let mut params = HashMap::new();
+	params.insert("per_page", option.per_page().to_string());
+	params.insert("page", option.page().to_string());
+	params
+}
+
+/// 创建和更新发布的请求体, 平台要求提供标签、标题和说明, 未设置时沿用 `current` 中的值,
+/// 创建发布时没有 `current`, 标题默认为标签名称, 说明默认为空
+fn release_body(
+	option: &ReleaseUpdateOptions,
+	current: Option<&ReleaseInfo>,
+) -> Result<serde_json::Value> {
+	if option.draft == Some(true) {
+		return Err(unsupported("draft".to_string()));
+	}
+	let tag_name = option
+		.tag_name
+		.as_deref()
+		.or(current.map(|current| current.tag_name.as_str()))
+		.unwrap_or_default();
+	let mut body = serde_json::json!({ "tag_name": tag_name });
+	if let Some(target_commitish) = &option.target_commitish {
+		body["target_commitish"] = target_commitish.as_str().into();
+	}
+	body["name"] = option
+		.name
+		.as_deref()
+		.or(current.and_then(|current| current.name.as_deref()))
+		.unwrap_or(tag_name)
+		.into();
+	body["body"] = option
+		.body
+		.as_deref()
+		.or(current.and_then(|current| current.body.as_deref()))
+		.unwrap_or_default()
+		.into();
+	if let Some(prerelease) = option.prerelease.or(current.map(|current| current.prerelease)) {
+		body["prerelease"] = prerelease.into();
+	}
+	Ok(body)
+}
+
+fn issues_params(option: &IssueListOptions) -> Result<HashMap<&'static str, String>> {
+	let mut params = HashMap::new();
+	params.insert("per_page", option.per_page().to_string());
+	params.insert("page", option.page().to_string());
+	if let Some(state) = option.state {
+		params.insert("state", state.to_string());
+	}
+	if let Some(labels) = &option.labels {
+		params.insert("labels", labels.join(","));
+	}
+	if let Some(assignee) = &option.assignee {
+		params.insert("assignee", assignee.to_string());
+	}
+	if let Some(creator) = &option.creator {
+		params.insert("creator", creator.to_string());
+	}
+	if let Some(since) = option.since {
+		params.insert("since", since.to_rfc3339());
+	}
+	match option.sort {
+		Some(IssueSort::Comments) => return Err(unsupported("sort=comments".to_string())),
+		Some(sort) => {
+			params.insert("sort", sort.to_string());
+		}
+		None => {}
+	}
+	if let Some(direction) = option.direction {
+		params.insert("direction", direction.to_string());
+	}
+	Ok(params)
+}
+
+/// 创建和更新 Issue 的请求体, 未设置的字段不会发送
+fn issue_body(repo: &str, option: &IssueUpdateOptions) -> Result<serde_json::Value> {
+	let mut body = serde_json::json!({ "repo": repo });
+	if let Some(title) = &option.title {
+		body["title"] = title.as_str().into();
+	}
+	if let Some(issue_body) = &option.body {
+		body["body"] = issue_body.as_str().into();
+	}
+	if let Some(labels) = &option.labels {
+		body["labels"] = labels.join(",").into();
+	}
+	if let Some(assignees) = &option.assignees {
+		body["assignee"] = match assignees.as_slice() {
+			[] => "".into(),
+			[assignee] => assignee.as_str().into(),
+			_ => return Err(unsupported("multiple assignees".to_string())),
+		};
+	}
+	if let Some(milestone) = option.milestone {
+		body["milestone"] = milestone.into();
+	}
+	Ok(body)
+}
+
+/// 评论接口中 Issue 和 PR 的路径片段
+fn comment_path(target: CommentTarget) -> &'static str {
+	match target {
+		CommentTarget::Issue => "issues",
+		CommentTarget::PullRequest => "pulls",
+	}
+}
+
+/// PR 的评论接口同时返回普通评论和代码评论, 通过 `comment_type` 区分
+fn comments_params(
+	option: &CommentListOptions,
+	target: CommentTarget,
+	comment_type: &str,
+) -> HashMap<&'static str, String> {
+	let mut params = HashMap::new();
+	params.insert("per_page", option.per_page().to_string());
+	params.insert("page", option.page().to_string());
+	if target == CommentTarget::PullRequest {
+		params.insert("comment_type", comment_type.to_string());
+	}
+	params
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn current_release() -> ReleaseInfo {
+		ReleaseInfo {
+			id: "1".to_string(),
+			tag_name: "v1.0.0".to_string(),
+			target_commitish: Some("main".to_string()),
+			name: Some("First release".to_string()),
+			body: Some("notes".to_string()),
+			draft: false,
+			prerelease: true,
+			author: None,
+			created_at: None,
+			published_at: None,
+			assets: Vec::new(),
+			raw: None,
+		}
+	}
+
+	#[test]
+	fn release_update_keeps_unset_fields() {
+		let option =
+			ReleaseUpdateOptions { name: Some("Renamed".to_string()), ..Default::default() };
+		let body = release_body(&option, Some(&current_release())).unwrap();
+		assert_eq!(
+			body,
+			serde_json::json!({
+				"tag_name": "v1.0.0",
+				"name": "Renamed",
+				"body": "notes",
+				"prerelease": true,
+			})
+		);
+	}
+
+	#[test]
+	fn release_create_uses_defaults() {
+		let option = ReleaseOptions { tag_name: "v2.0.0".to_string(), ..Default::default() };
+		let body = release_body(&option.into(), None).unwrap();
+		assert_eq!(body, serde_json::json!({ "tag_name": "v2.0.0", "name": "v2.0.0", "body": "" }));
+		let draft = ReleaseUpdateOptions { draft: Some(true), ..Default::default() };
+		assert!(matches!(release_body(&draft, None), Err(Error::Unsupported { .. })));
+	}
 }
@@ -1,6 +1,7 @@
+use crate::model::ErrorBody;
 use async_trait::async_trait;
 use http::Extensions;
-use nipaw_core::Error as CoreError;
+use nipaw_core::{Error as CoreError, types::rate_limit::RateLimitInfo};
 use reqwest::{Request, Response, StatusCode};
 use reqwest_middleware::{Error, Middleware, Next, Result};
 
@@ -16,13 +17,31 @@ impl Middleware for AuthMiddleware {
 	) -> Result<Response> {
 		let res = next.run(req, extensions).await?;
 		match res.status() {
-			StatusCode::OK => Ok(res),
+			status if status.is_success() => Ok(res),
 			StatusCode::UNAUTHORIZED => Err(Error::Middleware(CoreError::Unauthorized.into())),
 			StatusCode::NOT_FOUND => Err(Error::Middleware(CoreError::NotFound.into())),
 			StatusCode::FORBIDDEN | StatusCode::TOO_MANY_REQUESTS => {
-				Err(Error::Middleware(CoreError::RateLimit.into()))
+				let rate_limit = RateLimitInfo::from_headers(res.headers());
+				if res.status() == StatusCode::TOO_MANY_REQUESTS || rate_limit.is_exhausted() {
+					Err(Error::Middleware(CoreError::RateLimit(rate_limit).into()))
+				} else {
+					Err(Error::Middleware(api_error(res).await.into()))
+				}
+			}
+			status if status.is_client_error() || status.is_server_error() => {
+				Err(Error::Middleware(api_error(res).await.into()))
 			}
 			_ => Ok(res),
 		}
 	}
 }
+
+/// 解析错误响应体, 构造 [CoreError::Api]
+async fn api_error(res: Response) -> CoreError {
+	let status = res.status();
+	let request_id =
+		res.headers().get("x-request-id").and_then(|value| value.to_str().ok()).map(str::to_string);
+	let raw_body = res.bytes().await.unwrap_or_default();
+	let body = serde_json::from_slice::<ErrorBody>(&raw_body).map(Into::into).unwrap_or_default();
+	CoreError::api(status, request_id, body, &raw_body)
+}
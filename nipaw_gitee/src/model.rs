@@ -0,0 +1,213 @@
+//! Gitee API 的原始响应模型
+//!
+//! 字段与 Gitee OpenAPI v5 保持一致, 客户端返回的统一类型均由这些模型转换而来
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// 用户信息, 对应 `/user` 和 `/users/{username}`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct User {
+	pub id: u64,
+	pub login: String,
+	pub name: Option<String>,
+	pub email: Option<String>,
+	pub avatar_url: String,
+	pub followers: u64,
+	pub following: u64,
+	pub public_repos: u64,
+}
+
+/// 仓库、提交等数据中引用的账号
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Account {
+	pub login: String,
+	pub avatar_url: String,
+}
+
+/// 仓库信息, 对应 `/repos/{owner}/{repo}` 及各仓库列表接口
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Repo {
+	pub id: u64,
+	pub owner: Account,
+	pub name: String,
+	pub full_name: String,
+	pub description: Option<String>,
+	pub public: Option<bool>,
+	pub fork: Option<bool>,
+	pub forks_count: Option<u64>,
+	pub language: Option<String>,
+	pub stargazers_count: Option<u64>,
+	pub default_branch: String,
+	pub created_at: DateTime<Utc>,
+	pub updated_at: DateTime<Utc>,
+	pub pushed_at: DateTime<Utc>,
+}
+
+/// 组织信息, 对应网页端的 `/orgs/{org}`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Org {
+	pub id: u64,
+	pub login: String,
+	pub name: Option<String>,
+	pub email: Option<String>,
+	pub avatar_url: String,
+	pub description: Option<String>,
+	pub follow_count: Option<u64>,
+}
+
+/// 提交信息, 对应 `/repos/{owner}/{repo}/commits/{ref}` 及提交列表接口
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Commit {
+	pub sha: String,
+	pub commit: CommitDetail,
+	/// 提交作者对应的 Gitee 账号, 作者邮箱未关联账号时为空
+	pub author: Option<Account>,
+	/// 提交者对应的 Gitee 账号, 提交者邮箱未关联账号时为空
+	pub committer: Option<Account>,
+	/// 提交统计, 仅获取单个提交时返回
+	pub stats: Option<CommitStats>,
+}
+
+/// Git 提交数据
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CommitDetail {
+	pub author: CommitSignature,
+	pub committer: CommitSignature,
+	pub message: String,
+}
+
+/// Git 提交签名
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CommitSignature {
+	pub name: String,
+	pub email: Option<String>,
+	pub date: DateTime<Utc>,
+}
+
+/// 提交统计
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CommitStats {
+	pub total: Option<u64>,
+	pub additions: Option<u64>,
+	pub deletions: Option<u64>,
+}
+
+/// 仓库协作者, 对应 `PUT /repos/{owner}/{repo}/collaborators/{username}`
+pub type Collaborator = Account;
+
+/// 错误响应体, 对应所有非成功响应
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ErrorBody {
+	pub message: Option<String>,
+	/// OAuth 相关错误的错误码
+	pub error: Option<String>,
+	/// OAuth 相关错误的描述
+	pub error_description: Option<String>,
+}
+
+/// 分支信息, 对应 `/repos/{owner}/{repo}/branches` 和 `/repos/{owner}/{repo}/branches/{branch}`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Branch {
+	pub name: String,
+	pub commit: BranchCommit,
+	pub protected: Option<bool>,
+}
+
+/// 分支指向的提交
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BranchCommit {
+	pub sha: String,
+}
+
+/// 标签, 对应 `/repos/{owner}/{repo}/tags`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Tag {
+	pub name: String,
+	pub message: Option<String>,
+	pub commit: TagCommit,
+	/// 标签创建者, 仅附注标签返回
+	pub tagger: Option<Tagger>,
+}
+
+/// 标签指向的提交
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TagCommit {
+	pub sha: String,
+}
+
+/// 附注标签的创建者
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Tagger {
+	pub name: String,
+	pub email: Option<String>,
+	pub date: Option<DateTime<Utc>>,
+}
+
+/// 发布信息, 对应 `/repos/{owner}/{repo}/releases` 及单个发布接口
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Release {
+	pub id: u64,
+	pub tag_name: String,
+	pub target_commitish: Option<String>,
+	pub name: Option<String>,
+	pub body: Option<String>,
+	#[serde(default)]
+	pub prerelease: bool,
+	pub author: Option<Account>,
+	pub created_at: Option<DateTime<Utc>>,
+	/// 附件列表, 平台自动生成的源码包没有 `id`
+	#[serde(default)]
+	pub assets: Vec<Asset>,
+}
+
+/// 发布附件, 对应 `/repos/{owner}/{repo}/releases/{release_id}/attach_files`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Asset {
+	pub id: Option<u64>,
+	pub name: String,
+	pub size: Option<u64>,
+	pub browser_download_url: String,
+}
+
+/// Issue 信息, 对应 `/repos/{owner}/{repo}/issues` 及单个 Issue 接口
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Issue {
+	pub number: String,
+	pub title: String,
+	pub body: Option<String>,
+	pub state: String,
+	#[serde(default)]
+	pub labels: Vec<Label>,
+	pub assignee: Option<Account>,
+	pub milestone: Option<Milestone>,
+	pub user: Option<Account>,
+	pub created_at: DateTime<Utc>,
+	pub updated_at: DateTime<Utc>,
+	/// 关闭时间
+	pub finished_at: Option<DateTime<Utc>>,
+}
+
+/// 标签
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Label {
+	pub name: String,
+}
+
+/// 里程碑
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Milestone {
+	pub title: String,
+}
+
+/// 评论, 对应 Issue 评论和 PR 评论接口
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Comment {
+	pub id: u64,
+	pub body: String,
+	pub user: Option<Account>,
+	/// 评论所在的文件路径, 仅 PR 代码评论返回
+	pub path: Option<String>,
+	pub created_at: DateTime<Utc>,
+	pub updated_at: Option<DateTime<Utc>>,
+}
@@ -1,7 +1,18 @@
-use chrono::{NaiveDate, Utc, Weekday};
+use crate::model::{
+	Account, Asset, Branch, Comment, Commit, CommitSignature, CommitStats, ErrorBody, GitRef,
+	GitTag, Invitation, Issue, Org, RateLimit, Release, Repo, Tag, User,
+};
+use chrono::{DateTime, NaiveDate, Utc, Weekday};
 use itertools::Itertools;
+use nipaw_core::error::ApiErrorBody;
+use nipaw_core::types::branch::BranchInfo;
 use nipaw_core::types::collaborator::CollaboratorResult;
+use nipaw_core::types::comment::CommentInfo;
+use nipaw_core::types::issue::{IssueInfo, IssueState};
+use nipaw_core::types::rate_limit::RateLimitInfo;
+use nipaw_core::types::release::{ReleaseAsset, ReleaseInfo};
 use nipaw_core::types::repo::Visibility;
+use nipaw_core::types::tag::{TagInfo, TaggerInfo};
 use nipaw_core::types::{
 	commit::{CommitData, CommitInfo, StatsInfo, UserInfo as CommitUserInfo},
 	org::OrgInfo,
@@ -9,81 +20,42 @@ use nipaw_core::types::{
 	user::{ContributionData, ContributionResult, UserInfo},
 };
 use scraper::Selector;
-use serde::{Deserialize, Serialize};
-use serde_json::Value;
-
-#[derive(Serialize, Deserialize)]
-pub(crate) struct JsonValue(pub(crate) Value);
 
-impl From<JsonValue> for UserInfo {
-	fn from(json_value: JsonValue) -> Self {
-		let user_info = json_value.0;
+impl From<User> for UserInfo {
+	fn from(user: User) -> Self {
 		UserInfo {
-			id: user_info.get("id").and_then(|v| v.as_u64()).unwrap().to_string(),
-			login: user_info.get("login").and_then(|v| v.as_str()).unwrap().to_string(),
-			name: user_info.get("name").and_then(|v| v.as_str()).map(|s| s.to_string()),
-			avatar_url: user_info.get("avatar_url").and_then(|v| v.as_str()).unwrap().to_string(),
-			email: user_info.get("email").and_then(|v| v.as_str()).map(|s| s.to_string()),
-			followers: user_info.get("followers").and_then(|v| v.as_u64()).unwrap(),
-			following: user_info.get("following").and_then(|v| v.as_u64()).unwrap(),
-			public_repo_count: user_info.get("public_repos").and_then(|v| v.as_u64()).unwrap(),
-		}
-	}
-}
-
-impl From<JsonValue> for RepoInfo {
-	fn from(json_value: JsonValue) -> Self {
-		let repo_info = json_value.0;
-		let is_public = repo_info
-			.get("visibility")
-			.and_then(|v| v.as_str())
-			.map(|s| s.to_lowercase() == "public")
-			.unwrap_or(false);
+			id: user.id.to_string(),
+			login: user.login,
+			name: user.name,
+			avatar_url: user.avatar_url,
+			email: user.email,
+			followers: user.followers,
+			following: user.following,
+			public_repo_count: user.public_repos,
+			raw: None,
+		}
+	}
+}
+
+impl From<Repo> for RepoInfo {
+	fn from(repo: Repo) -> Self {
+		let is_public = repo.visibility.is_some_and(|s| s.to_lowercase() == "public");
 		RepoInfo {
-			id: repo_info.get("id").and_then(|v| v.as_u64()).unwrap().to_string(),
-			owner: repo_info
-				.get("owner")
-				.and_then(|v| v.get("login"))
-				.and_then(|v| v.as_str())
-				.unwrap()
-				.to_string(),
-			name: repo_info.get("name").and_then(|v| v.as_str()).unwrap().to_string(),
-			full_name: repo_info.get("full_name").and_then(|v| v.as_str()).unwrap().to_string(),
-			description: repo_info
-				.get("description")
-				.and_then(|v| v.as_str())
-				.map(|s| s.to_string()),
+			id: repo.id.to_string(),
+			owner: repo.owner.login,
+			name: repo.name,
+			full_name: repo.full_name,
+			description: repo.description,
 			visibility: if is_public { Visibility::Public } else { Visibility::Private },
-			fork: repo_info.get("fork").and_then(|v| v.as_bool()).unwrap_or(false),
-			fork_count: repo_info.get("forks_count").and_then(|v| v.as_u64()).unwrap_or(0),
-			language: repo_info.get("language").and_then(|v| v.as_str()).map(|s| s.to_string()),
-			star_count: repo_info.get("stargazers_count").and_then(|v| v.as_u64()).unwrap_or(0),
-			default_branch: repo_info
-				.get("default_branch")
-				.and_then(|v| v.as_str())
-				.unwrap()
-				.to_string(),
-			created_at: repo_info
-				.get("created_at")
-				.and_then(|v| v.as_str())
-				.unwrap()
-				.to_string()
-				.parse()
-				.unwrap(),
-			updated_at: repo_info
-				.get("updated_at")
-				.and_then(|v| v.as_str())
-				.unwrap()
-				.to_string()
-				.parse()
-				.unwrap(),
-			pushed_at: repo_info
-				.get("pushed_at")
-				.and_then(|v| v.as_str())
-				.unwrap()
-				.to_string()
-				.parse()
-				.unwrap(),
+			fork: repo.fork.unwrap_or(false),
+			fork_count: repo.forks_count.unwrap_or(0),
+			language: repo.language,
+			star_count: repo.stargazers_count.unwrap_or(0),
+			default_branch: repo.default_branch,
+			created_at: repo.created_at,
+			updated_at: repo.updated_at,
+			pushed_at: repo.pushed_at,
+			raw: None,
 		}
 	}
 }
@@ -158,97 +130,405 @@ impl From<Html> for ContributionResult {
 	}
 }
 
-impl From<JsonValue> for CommitInfo {
-	fn from(value: JsonValue) -> Self {
-		let commit_info = value.0;
-		let commit_value = commit_info.get("commit").unwrap().clone();
-		let stats_value = commit_info.get("stats").unwrap().clone();
+impl From<Commit> for CommitInfo {
+	fn from(commit: Commit) -> Self {
+		let avatar_url = |account: Option<Account>| {
+			account.map(|account| account.avatar_url).unwrap_or_default()
+		};
 		CommitInfo {
-			sha: commit_info.get("sha").and_then(|v| v.as_str()).unwrap().to_string(),
-			commit: JsonValue(commit_value).into(),
-			stats: JsonValue(stats_value).into(),
+			sha: commit.sha,
+			commit: CommitData {
+				author: commit_user_info(commit.commit.author, avatar_url(commit.author)),
+				committer: commit_user_info(commit.commit.committer, avatar_url(commit.committer)),
+				message: commit.commit.message,
+			},
+			stats: commit.stats.map(Into::into).unwrap_or_default(),
+			raw: None,
 		}
 	}
 }
 
-impl From<JsonValue> for CommitData {
-	fn from(value: JsonValue) -> Self {
-		let commit_data = value.0;
-		let author_value = commit_data.get("author").unwrap().clone();
-		let committer_value = commit_data.get("committer").unwrap().clone();
-		CommitData {
-			author: JsonValue(author_value).into(),
-			committer: JsonValue(committer_value).into(),
-			message: commit_data.get("message").and_then(|v| v.as_str()).unwrap().to_string(),
+fn commit_user_info(signature: CommitSignature, avatar_url: String) -> CommitUserInfo {
+	CommitUserInfo {
+		name: signature.name,
+		email: signature.email,
+		avatar_url,
+		date: signature.date,
+	}
+}
+
+impl From<CommitStats> for StatsInfo {
+	fn from(stats: CommitStats) -> Self {
+		StatsInfo {
+			total: stats.total.unwrap_or(0),
+			additions: stats.additions.unwrap_or(0),
+			deletions: stats.deletions.unwrap_or(0),
 		}
 	}
 }
 
-impl From<JsonValue> for CommitUserInfo {
-	fn from(value: JsonValue) -> Self {
-		let user_info = value.0;
-		CommitUserInfo {
-			name: user_info.get("name").and_then(|v| v.as_str()).unwrap().to_string(),
-			email: user_info.get("email").and_then(|v| v.as_str()).map(|s| s.to_string()),
-			avatar_url: user_info.get("avatar_url").and_then(|v| v.as_str()).unwrap().to_string(),
-			date: user_info
-				.get("date")
-				.and_then(|v| v.as_str())
-				.unwrap()
-				.to_string()
-				.parse()
-				.unwrap(),
+impl From<Org> for OrgInfo {
+	fn from(org: Org) -> Self {
+		OrgInfo {
+			id: org.id,
+			login: org.login,
+			name: org.name,
+			email: org.email,
+			avatar_url: org.avatar_url,
+			description: org.description,
+			follow_count: org.follow_count.unwrap_or(0),
+			raw: None,
 		}
 	}
 }
 
-impl From<JsonValue> for StatsInfo {
-	fn from(value: JsonValue) -> Self {
-		let stats_info = value.0;
-		StatsInfo {
-			total: stats_info.get("total").and_then(|v| v.as_u64()).unwrap_or(0),
-			additions: stats_info.get("additions").and_then(|v| v.as_u64()).unwrap_or(0),
-			deletions: stats_info.get("deletions").and_then(|v| v.as_u64()).unwrap_or(0),
+impl From<Invitation> for CollaboratorResult {
+	fn from(invitation: Invitation) -> Self {
+		CollaboratorResult {
+			login: invitation.inviter.login,
+			avatar_url: invitation.inviter.avatar_url,
 		}
 	}
 }
 
-impl From<JsonValue> for OrgInfo {
-	fn from(value: JsonValue) -> Self {
-		let org_info = value.0;
-		OrgInfo {
-			id: org_info.get("id").and_then(|v| v.as_u64()).unwrap(),
-			login: org_info.get("login").and_then(|v| v.as_str()).unwrap().to_string(),
-			name: org_info.get("name").and_then(|v| v.as_str()).map(|s| s.to_string()),
-			email: org_info.get("email").and_then(|v| v.as_str()).map(|s| s.to_string()),
-			avatar_url: org_info.get("avatar_url").and_then(|v| v.as_str()).unwrap().to_string(),
-			description: org_info
-				.get("description")
-				.and_then(|v| v.as_str())
-				.map(|s| s.to_string()),
-			follow_count: org_info.get("follow_count").and_then(|v| v.as_u64()).unwrap_or(0),
+impl From<ErrorBody> for ApiErrorBody {
+	fn from(body: ErrorBody) -> Self {
+		ApiErrorBody {
+			code: body.errors.into_iter().find_map(|detail| detail.code),
+			message: body.message,
+			documentation_url: body.documentation_url,
 		}
 	}
 }
 
-impl From<JsonValue> for CollaboratorResult {
-	fn from(json_value: JsonValue) -> Self {
-		let collaborator = json_value.0;
-		CollaboratorResult {
-			login: collaborator
-				.get("inviter")
-				.unwrap()
-				.get("login")
-				.and_then(|v| v.as_str())
-				.unwrap()
-				.to_string(),
-			avatar_url: collaborator
-				.get("inviter")
-				.unwrap()
-				.get("avatar_url")
-				.and_then(|v| v.as_str())
-				.unwrap()
-				.to_string(),
+impl From<RateLimit> for RateLimitInfo {
+	fn from(rate_limit: RateLimit) -> Self {
+		RateLimitInfo {
+			limit: Some(rate_limit.rate.limit),
+			remaining: Some(rate_limit.rate.remaining),
+			reset_at: DateTime::from_timestamp(rate_limit.rate.reset, 0),
+			retry_after: None,
+		}
+	}
+}
+
+impl From<Branch> for BranchInfo {
+	fn from(branch: Branch) -> Self {
+		BranchInfo {
+			name: branch.name,
+			sha: branch.commit.sha,
+			protected: branch.protected.unwrap_or(false),
+			raw: None,
+		}
+	}
+}
+
+impl From<GitRef> for BranchInfo {
+	fn from(git_ref: GitRef) -> Self {
+		BranchInfo {
+			name: git_ref.ref_name.trim_start_matches("refs/heads/").to_string(),
+			sha: git_ref.object.sha,
+			protected: false,
+			raw: None,
+		}
+	}
+}
+
+impl From<Tag> for TagInfo {
+	fn from(tag: Tag) -> Self {
+		TagInfo {
+			name: tag.name,
+			sha: tag.commit.sha,
+			tagger: None,
+			message: None,
+			annotated: false,
+			raw: None,
+		}
+	}
+}
+
+impl From<GitRef> for TagInfo {
+	fn from(git_ref: GitRef) -> Self {
+		TagInfo {
+			name: git_ref.ref_name.trim_start_matches("refs/tags/").to_string(),
+			sha: git_ref.object.sha,
+			tagger: None,
+			message: None,
+			annotated: false,
+			raw: None,
+		}
+	}
+}
+
+impl From<GitTag> for TagInfo {
+	fn from(tag: GitTag) -> Self {
+		TagInfo {
+			name: tag.tag,
+			sha: tag.object.sha,
+			tagger: Some(TaggerInfo {
+				name: tag.tagger.name,
+				email: tag.tagger.email,
+				date: Some(tag.tagger.date),
+			}),
+			message: Some(tag.message),
+			annotated: true,
+			raw: None,
+		}
+	}
+}
+
+impl From<Release> for ReleaseInfo {
+	fn from(release: Release) -> Self {
+		ReleaseInfo {
+			id: release.id.to_string(),
+			tag_name: release.tag_name,
+			target_commitish: release.target_commitish,
+			name: release.name,
+			body: release.body,
+			draft: release.draft,
+			prerelease: release.prerelease,
+			author: release.author.map(|author| author.login),
+			created_at: release.created_at,
+			published_at: release.published_at,
+			assets: release.assets.into_iter().map(Into::into).collect(),
+			raw: None,
+		}
+	}
+}
+
+impl From<Asset> for ReleaseAsset {
+	fn from(asset: Asset) -> Self {
+		ReleaseAsset {
+			id: asset.id.to_string(),
+			name: asset.name,
+			size: Some(asset.size),
+			content_type: asset.content_type,
+			download_url: asset.browser_download_url,
+			download_count: Some(asset.download_count),
+		}
+	}
+}
+
+impl From<Issue> for IssueInfo {
+	fn from(issue: Issue) -> Self {
+		IssueInfo {
+			number: issue.number.to_string(),
+			title: issue.title,
+			body: issue.body,
+			state: if issue.state == "closed" { IssueState::Closed } else { IssueState::Open },
+			labels: issue.labels.into_iter().map(|label| label.name).collect(),
+			assignees: issue.assignees.into_iter().map(|assignee| assignee.login).collect(),
+			milestone: issue.milestone.map(|milestone| milestone.title),
+			author: issue.user.map(|user| user.login),
+			pull_request: issue.pull_request.is_some(),
+			created_at: issue.created_at,
+			updated_at: issue.updated_at,
+			closed_at: issue.closed_at,
+			raw: None,
+		}
+	}
+}
+
+impl From<Comment> for CommentInfo {
+	fn from(comment: Comment) -> Self {
+		CommentInfo {
+			id: comment.id.to_string(),
+			body: comment.body,
+			author: comment.user.map(|user| user.login),
+			path: comment.path,
+			line: comment.line,
+			created_at: comment.created_at,
+			updated_at: Some(comment.updated_at),
+			raw: None,
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use nipaw_core::decode;
+
+	#[test]
+	fn decodes_branch() {
+		let branch: Branch = decode::from_slice(
+			br#"{
+				"name": "main",
+				"commit": { "sha": "6dcb09b5b57875f334f61aebed695e2e4193db5e", "url": "https://api.github.com/repos/o/r/commits/6dcb09b" },
+				"protected": true
+			}"#,
+		)
+		.unwrap();
+		let branch = BranchInfo::from(branch);
+		assert_eq!(branch.name, "main");
+		assert_eq!(branch.sha, "6dcb09b5b57875f334f61aebed695e2e4193db5e");
+		assert!(branch.protected);
+
+		let git_ref: GitRef = decode::from_slice(
+			br#"{ "ref": "refs/heads/feature/a", "object": { "sha": "aa218f56", "type": "commit" } }"#,
+		)
+		.unwrap();
+		let branch = BranchInfo::from(git_ref);
+		assert_eq!(branch.name, "feature/a");
+		assert!(!branch.protected);
+	}
+
+	#[test]
+	fn decodes_tags() {
+		let tag: Tag = decode::from_slice(
+			br#"{ "name": "v1.0.0", "commit": { "sha": "c5b97d5a", "url": "https://api.github.com/repos/o/r/commits/c5b97d5a" }, "zipball_url": "https://github.com/o/r/zipball/v1.0.0" }"#,
+		)
+		.unwrap();
+		let tag = TagInfo::from(tag);
+		assert_eq!((tag.name.as_str(), tag.sha.as_str()), ("v1.0.0", "c5b97d5a"));
+		assert!(!tag.annotated && tag.tagger.is_none());
+
+		let git_tag: GitTag = decode::from_slice(
+			br#"{
+				"tag": "v2.0.0",
+				"sha": "940bd336",
+				"message": "Release v2.0.0\n",
+				"tagger": { "name": "Monalisa", "email": "octocat@github.com", "date": "2014-11-07T22:01:45Z" },
+				"object": { "sha": "c3d0be41", "type": "commit" }
+			}"#,
+		)
+		.unwrap();
+		let tag = TagInfo::from(git_tag);
+		assert_eq!(tag.name, "v2.0.0");
+		// 附注标签的SHA为其指向的提交, 而非标签对象自身
+		assert_eq!(tag.sha, "c3d0be41");
+		assert_eq!(tag.message.as_deref(), Some("Release v2.0.0\n"));
+		assert!(tag.annotated);
+		let tagger = tag.tagger.unwrap();
+		assert_eq!(tagger.name, "Monalisa");
+		assert_eq!(tagger.date, DateTime::from_timestamp(1_415_397_705, 0));
+	}
+
+	#[test]
+	fn decodes_release_with_assets() {
+		let release: Release = decode::from_slice(
+			br#"{
+				"id": 1,
+				"tag_name": "v1.0.0",
+				"target_commitish": "master",
+				"name": "v1.0.0",
+				"body": "Description of the release",
+				"draft": false,
+				"prerelease": true,
+				"author": { "login": "octocat", "avatar_url": "https://github.com/images/error/octocat_happy.gif" },
+				"created_at": "2013-02-27T19:35:32Z",
+				"published_at": null,
+				"upload_url": "https://uploads.github.com/repos/o/r/releases/1/assets{?name,label}",
+				"assets": [{
+					"id": 2,
+					"name": "example.zip",
+					"size": 1024,
+					"content_type": "application/zip",
+					"browser_download_url": "https://github.com/o/r/releases/download/v1.0.0/example.zip",
+					"download_count": 42
+				}]
+			}"#,
+		)
+		.unwrap();
+		let release = ReleaseInfo::from(release);
+		assert_eq!(release.id, "1");
+		assert_eq!(release.tag_name, "v1.0.0");
+		assert!(release.prerelease && !release.draft);
+		assert_eq!(release.author.as_deref(), Some("octocat"));
+		assert_eq!(release.created_at, DateTime::from_timestamp(1_361_993_732, 0));
+		assert_eq!(release.published_at, None);
+		let asset = &release.assets[0];
+		assert_eq!(asset.id, "2");
+		assert_eq!(asset.size, Some(1024));
+		assert_eq!(asset.download_count, Some(42));
+		assert_eq!(
+			asset.download_url,
+			"https://github.com/o/r/releases/download/v1.0.0/example.zip"
+		);
+	}
+
+	#[test]
+	fn decodes_issue() {
+		let issue: Issue = decode::from_slice(
+			br#"{
+				"number": 1347,
+				"title": "Found a bug",
+				"body": "I'm having a problem with this.",
+				"state": "closed",
+				"labels": [{ "id": 208045946, "name": "bug" }],
+				"assignees": [{ "login": "octocat", "avatar_url": "https://github.com/images/error/octocat_happy.gif" }],
+				"milestone": { "number": 1, "title": "v1.0" },
+				"user": { "login": "hubot", "avatar_url": "https://github.com/images/error/hubot_happy.gif" },
+				"created_at": "2011-04-22T13:33:48Z",
+				"updated_at": "2011-04-22T13:33:48Z",
+				"closed_at": "2011-04-23T13:33:48Z"
+			}"#,
+		)
+		.unwrap();
+		let issue = IssueInfo::from(issue);
+		assert_eq!(issue.number, "1347");
+		assert_eq!(issue.state, IssueState::Closed);
+		assert_eq!(issue.labels, ["bug"]);
+		assert_eq!(issue.assignees, ["octocat"]);
+		assert_eq!(issue.milestone.as_deref(), Some("v1.0"));
+		assert_eq!(issue.author.as_deref(), Some("hubot"));
+		assert!(!issue.pull_request);
+		assert!(issue.closed_at.is_some());
+	}
+
+	#[test]
+	fn marks_pull_requests_in_issue_list() {
+		let issue: Issue = decode::from_slice(
+			br#"{
+				"number": 1348,
+				"title": "Fix the bug",
+				"state": "open",
+				"pull_request": { "url": "https://api.github.com/repos/o/r/pulls/1348" },
+				"created_at": "2011-04-22T13:33:48Z",
+				"updated_at": "2011-04-22T13:33:48Z"
+			}"#,
+		)
+		.unwrap();
+		let issue = IssueInfo::from(issue);
+		assert_eq!(issue.state, IssueState::Open);
+		assert!(issue.pull_request);
+		assert!(issue.labels.is_empty() && issue.assignees.is_empty());
+	}
+
+	#[test]
+	fn decodes_comments() {
+		let comment: Comment = decode::from_slice(
+			br#"{
+				"id": 1,
+				"body": "Me too",
+				"user": { "login": "octocat", "avatar_url": "https://github.com/images/error/octocat_happy.gif" },
+				"created_at": "2011-04-14T16:00:49Z",
+				"updated_at": "2011-04-14T16:00:49Z"
+			}"#,
+		)
+		.unwrap();
+		let comment = CommentInfo::from(comment);
+		assert_eq!(comment.id, "1");
+		assert_eq!(comment.body, "Me too");
+		assert_eq!(comment.author.as_deref(), Some("octocat"));
+		assert_eq!((comment.path, comment.line), (None, None));
+		assert_eq!(comment.updated_at, Some(comment.created_at));
+
+		let review_comment: Comment = decode::from_slice(
+			br#"{
+				"id": 10,
+				"body": "Great stuff!",
+				"user": null,
+				"path": "file1.txt",
+				"line": 2,
+				"created_at": "2011-04-14T16:00:49Z",
+				"updated_at": "2011-04-15T16:00:49Z"
+			}"#,
+		)
+		.unwrap();
+		let review_comment = CommentInfo::from(review_comment);
+		assert_eq!(review_comment.path.as_deref(), Some("file1.txt"));
+		assert_eq!(review_comment.line, Some(2));
+		assert_eq!(review_comment.author, None);
+	}
+}
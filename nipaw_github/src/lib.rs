@@ -1,41 +1,195 @@
 mod client;
 mod common;
 mod middleware;
+pub mod model;
 
-pub use nipaw_core::Client;
+pub use nipaw_core::{Client, ClientBuilder};
 
 use crate::{
-	client::{HTTP_CLIENT, PROXY_URL},
-	common::{Html, JsonValue},
+	client::build_http_client,
+	common::Html,
+	model::{
+		Asset, Branch, Comment, Commit, GitRef, GitTag, Invitation, Issue, Org, RateLimit, Release,
+		Repo, Tag, User,
+	},
 };
 use async_trait::async_trait;
+use futures::{StreamExt, TryStreamExt, future, stream::BoxStream};
 use nipaw_core::{
-	Result,
+	FromConfig, Result,
+	config::ClientConfig,
+	decode,
 	error::Error,
-	option::{CommitListOptions, OrgRepoListOptions, ReposListOptions},
+	metrics::Operation,
+	option::{
+		BranchListOptions, CommentListOptions, CommitListOptions, IssueListOptions, IssueOptions,
+		IssueUpdateOptions, OrgRepoListOptions, ReleaseListOptions, ReleaseOptions,
+		ReleaseUpdateOptions, RepoSort, RepoType, ReposListOptions, TagListOptions,
+	},
+	pagination::{NextPage, PagedClient, error_stream},
 	types::{
+		branch::BranchInfo,
 		collaborator::{CollaboratorPermission, CollaboratorResult},
+		comment::{CommentInfo, CommentTarget},
 		commit::CommitInfo,
+		issue::IssueInfo,
 		org::OrgInfo,
+		rate_limit::RateLimitInfo,
+		release::{AssetStream, AssetUpload, ReleaseAsset, ReleaseInfo},
 		repo::RepoInfo,
+		tag::TagInfo,
 		user::{ContributionResult, UserInfo},
 	},
 };
-use reqwest::{Url, header};
-use serde_json::Value;
+use reqwest::{StatusCode, Url, header};
+use reqwest_middleware::{ClientWithMiddleware, RequestBuilder};
 use std::collections::HashMap;
 
+const PLATFORM: &str = "GitHub";
 const API_URL: &str = "https://api.github.com";
 const BASE_URL: &str = "https://github.com";
+const AVATAR_URL: &str = "https://avatars.githubusercontent.com";
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone)]
 pub struct GitHubClient {
-	pub token: Option<String>,
+	api_url: String,
+	base_url: String,
+	avatar_url: String,
+	config: ClientConfig,
+	http: ClientWithMiddleware,
+}
+
+impl Default for GitHubClient {
+	fn default() -> Self {
+		Self::new()
+	}
 }
 
 impl GitHubClient {
 	pub fn new() -> Self {
-		Self::default()
+		Self::with_config(ClientConfig::default()).expect("default client config is valid")
+	}
+
+	/// 使用自定义配置创建客户端, 可用于对接 GitHub Enterprise Server
+	///
+	/// # 参数
+	///
+	/// * `config` - 客户端配置, 详见 [ClientConfig]
+	pub fn with_config(config: ClientConfig) -> Result<Self> {
+		config.validate()?;
+		let base_urls = &config.base_urls;
+		Ok(Self {
+			api_url: base_urls.api_url_or(API_URL)?,
+			base_url: base_urls.base_url_or(BASE_URL)?,
+			avatar_url: base_urls.avatar_url_or(AVATAR_URL)?,
+			http: build_http_client(&config)?,
+			config,
+		})
+	}
+
+	/// 获取当前客户端配置
+	pub fn config(&self) -> &ClientConfig {
+		&self.config
+	}
+
+	/// 替换客户端配置, 会重新创建该实例的 HTTP 客户端
+	///
+	/// # 参数
+	///
+	/// * `config` - 客户端配置, 详见 [ClientConfig]
+	pub fn set_config(&mut self, config: ClientConfig) -> Result<()> {
+		*self = Self::with_config(config)?;
+		Ok(())
+	}
+
+	/// 将分支名、标签名或提交SHA解析为提交SHA
+	async fn resolve_sha(
+		&self,
+		repo_path: (&str, &str),
+		reference: &str,
+		operation: &'static str,
+	) -> Result<String> {
+		let url =
+			format!("{}/repos/{}/{}/commits/{}", self.api_url, repo_path.0, repo_path.1, reference);
+		let mut request = self
+			.http
+			.get(url)
+			.with_extension(Operation(operation))
+			.header(header::ACCEPT, "application/vnd.github.sha");
+		if let Some(token) = &self.config.token {
+			request = request.bearer_auth(token);
+		}
+		let resp = request.send().await?;
+		Ok(resp.text().await?.trim().to_string())
+	}
+
+	/// 创建指向 `sha` 的 Git 引用, `ref_name` 为完整引用名, 如 `refs/heads/main`
+	async fn create_ref(
+		&self,
+		repo_path: (&str, &str),
+		ref_name: &str,
+		sha: &str,
+		operation: &'static str,
+	) -> Result<reqwest::Response> {
+		let url = format!("{}/repos/{}/{}/git/refs", self.api_url, repo_path.0, repo_path.1);
+		let mut request = self.http.post(url).with_extension(Operation(operation));
+		if let Some(token) = &self.config.token {
+			request = request.bearer_auth(token);
+		}
+		let body = serde_json::json!({
+			"ref": ref_name,
+			"sha": sha,
+		});
+		Ok(request
+			.header(header::CONTENT_TYPE, "application/json")
+			.body(body.to_string())
+			.send()
+			.await?)
+	}
+
+	/// 修改 Issue, 更新和关闭共用
+	async fn patch_issue(
+		&self,
+		repo_path: (&str, &str),
+		number: &str,
+		body: serde_json::Value,
+		operation: &'static str,
+	) -> Result<IssueInfo> {
+		let url =
+			format!("{}/repos/{}/{}/issues/{}", self.api_url, repo_path.0, repo_path.1, number);
+		let mut request = self.http.patch(url).with_extension(Operation(operation));
+		if let Some(token) = &self.config.token {
+			request = request.bearer_auth(token);
+		}
+		let resp = request
+			.header(header::CONTENT_TYPE, "application/json")
+			.body(body.to_string())
+			.send()
+			.await?;
+		decode::json_into::<Issue, _>(resp, self.config.include_raw).await
+	}
+}
+
+impl PagedClient for GitHubClient {
+	const PLATFORM: &'static str = PLATFORM;
+	const NEXT_PAGE: NextPage = NextPage::Link;
+
+	fn page_request(&self, operation: &'static str, url: &str) -> RequestBuilder {
+		let mut request = self.http.get(url).with_extension(Operation(operation));
+		if let Some(token) = &self.config.token {
+			request = request.bearer_auth(token);
+		}
+		request
+	}
+
+	fn include_raw(&self) -> bool {
+		self.config.include_raw
+	}
+}
+
+impl FromConfig for GitHubClient {
+	fn from_config(config: ClientConfig) -> Result<Self> {
+		Self::with_config(config)
 	}
 }
 
@@ -45,66 +199,67 @@ impl Client for GitHubClient {
 		if token.is_empty() {
 			return Err(Error::TokenEmpty);
 		}
-		self.token = Some(token.to_string());
+		self.config.token = Some(token.to_string());
 		Ok(())
 	}
 
 	fn set_proxy(&mut self, proxy: &str) -> Result<()> {
-		PROXY_URL.set(proxy.to_string()).unwrap();
-		Ok(())
+		let mut config = self.config.clone();
+		config.proxy = Some(proxy.to_string());
+		self.set_config(config)
 	}
 
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
 	async fn get_user_info(&self) -> Result<UserInfo> {
-		if self.token.is_none() {
+		if self.config.token.is_none() {
 			return Err(Error::TokenEmpty);
 		}
-		let url = format!("{}/user", API_URL);
-		let request = HTTP_CLIENT.get(url).bearer_auth(self.token.as_ref().unwrap());
+		let url = format!("{}/user", self.api_url);
+		let request = self
+			.http
+			.get(url)
+			.with_extension(Operation("get_user_info"))
+			.bearer_auth(self.config.token.as_ref().unwrap());
 		let resp = request.send().await?;
-		let user_info: JsonValue = resp.json().await?;
-		Ok(user_info.into())
+		decode::json_into::<User, _>(resp, self.config.include_raw).await
 	}
 
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
 	async fn get_user_info_with_name(&self, user_name: &str) -> Result<UserInfo> {
-		let url = format!("{}/users/{}", API_URL, user_name);
-		let mut request = HTTP_CLIENT.get(url);
-		if let Some(token) = &self.token {
+		let url = format!("{}/users/{}", self.api_url, user_name);
+		let mut request = self.http.get(url).with_extension(Operation("get_user_info_with_name"));
+		if let Some(token) = &self.config.token {
 			request = request.bearer_auth(token);
 		}
 		let resp = request.send().await?;
-		let user_info: JsonValue = resp.json().await?;
-		Ok(user_info.into())
+		decode::json_into::<User, _>(resp, self.config.include_raw).await
 	}
 
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
 	async fn get_user_avatar_url(&self, user_name: &str) -> Result<String> {
-		let url = format!("{}/{}", BASE_URL, user_name);
-		let request = HTTP_CLIENT.get(url).header("Accept", "image/*");
+		let url = format!("{}/users/{}", self.api_url, user_name);
+		let mut request = self.http.get(url).with_extension(Operation("get_user_avatar_url"));
+		if let Some(token) = &self.config.token {
+			request = request.bearer_auth(token);
+		}
 		let resp = request.send().await?;
-		let html: Html = Html::from(resp.text().await?);
-		let document = scraper::Html::parse_document(&html.0);
-
-		let selector =
-			scraper::Selector::parse("meta[name='octolytics-dimension-user_id']").unwrap();
-		let user_id = document
-			.select(&selector)
-			.next()
-			.and_then(|element| element.value().attr("content"))
-			.map(|id| id.to_string())
-			.unwrap();
-		let avatar_url = format!("https://avatars.githubusercontent.com/u/{}?v=4", user_id);
-		Ok(avatar_url)
+		let user: User = decode::json(resp).await?;
+		Ok(format!("{}/u/{}?v=4", self.avatar_url, user.id))
 	}
 
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
 	async fn get_user_contribution(&self, user_name: &str) -> Result<ContributionResult> {
-		let mut url = Url::parse(&format!("{}/{}", BASE_URL, user_name))?;
+		let mut url = Url::parse(&format!("{}/{}", self.base_url, user_name))?;
 		url.query_pairs_mut()
 			.append_pair("action", "show")
 			.append_pair("controller", "profiles")
 			.append_pair("tab", "contributions")
 			.append_pair("user_id", user_name);
 
-		let request = HTTP_CLIENT
+		let request = self
+			.http
 			.get(url)
+			.with_extension(Operation("get_user_contribution"))
 			.header("X-Requested-With", "XMLHttpRequest")
 			.header("Accept", "text/html");
 		let resp = request.send().await?;
@@ -112,109 +267,135 @@ impl Client for GitHubClient {
 		Ok(html.into())
 	}
 
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
 	async fn get_org_info(&self, org_name: &str) -> Result<OrgInfo> {
-		let url = format!("{}/orgs/{}", API_URL, org_name);
-		let mut request = HTTP_CLIENT.get(url);
-		if let Some(token) = &self.token {
+		let url = format!("{}/orgs/{}", self.api_url, org_name);
+		let mut request = self.http.get(url).with_extension(Operation("get_org_info"));
+		if let Some(token) = &self.config.token {
 			request = request.bearer_auth(token);
 		}
 		let resp = request.send().await?;
-		let org_info: JsonValue = resp.json().await?;
-		Ok(org_info.into())
+		decode::json_into::<Org, _>(resp, self.config.include_raw).await
 	}
 
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
 	async fn get_org_repos(
 		&self,
 		org_name: &str,
 		option: Option<OrgRepoListOptions>,
 	) -> Result<Vec<RepoInfo>> {
-		let url = format!("{}/orgs/{}/repos", API_URL, org_name);
-		let mut request = HTTP_CLIENT.get(url);
-		let mut params = HashMap::new();
-		if let Some(token) = &self.token {
-			request = request.bearer_auth(token);
-		}
-		if let Some(option) = option {
-			let per_page = option.per_page.unwrap_or_default().min(100);
-			params.insert("per_page", per_page.to_string());
-			let page = option.page.unwrap_or_default();
-			params.insert("page", page.to_string());
-		}
-		let resp = request.send().await?;
-		let repo_infos: Vec<JsonValue> = resp.json().await?;
-		Ok(repo_infos.into_iter().map(|v| v.into()).collect())
+		let url = format!("{}/orgs/{}/repos", self.api_url, org_name);
+		let option = option.unwrap_or_default();
+		let params = org_repos_params(&option);
+		Ok(self.get_page::<Repo, _>("get_org_repos", &url, &params, option.timeout).await?.items)
+	}
+
+	fn get_org_repos_stream<'a>(
+		&'a self,
+		org_name: &'a str,
+		option: Option<OrgRepoListOptions>,
+		limit: Option<usize>,
+	) -> BoxStream<'a, Result<RepoInfo>> {
+		let url = format!("{}/orgs/{}/repos", self.api_url, org_name);
+		let option = option.unwrap_or_default();
+		self.paginate::<Repo, _>(
+			"get_org_repos_stream",
+			url,
+			org_repos_params(&option),
+			option.page(),
+			limit,
+			option.timeout,
+		)
 	}
 
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
 	async fn get_org_avatar_url(&self, org_name: &str) -> Result<String> {
-		let url = format!("{}/orgs/{}", API_URL, org_name);
-		let request = HTTP_CLIENT.get(url);
+		let url = format!("{}/orgs/{}", self.api_url, org_name);
+		let mut request = self.http.get(url).with_extension(Operation("get_org_avatar_url"));
+		if let Some(token) = &self.config.token {
+			request = request.bearer_auth(token);
+		}
 		let resp = request.send().await?;
-		let org_html = resp.text().await?;
-
-		let document = scraper::Html::parse_document(&org_html);
-		let selector = scraper::Selector::parse("meta[name='hovercard-subject-tag']").unwrap();
-		let element = document.select(&selector).next().unwrap();
-		let org_id = element.value().attr("content").unwrap();
-		let avatar_url = format!("https://avatars.githubusercontent.com/u/{}?v=4", org_id);
-		Ok(avatar_url)
+		let org: Org = decode::json(resp).await?;
+		Ok(format!("{}/u/{}?v=4", self.avatar_url, org.id))
 	}
 
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
 	async fn get_repo_info(&self, repo_path: (&str, &str)) -> Result<RepoInfo> {
-		let url = format!("{}/repos/{}/{}", API_URL, repo_path.0, repo_path.1);
-		let mut request = HTTP_CLIENT.get(url);
-		if let Some(token) = &self.token {
+		let url = format!("{}/repos/{}/{}", self.api_url, repo_path.0, repo_path.1);
+		let mut request = self.http.get(url).with_extension(Operation("get_repo_info"));
+		if let Some(token) = &self.config.token {
 			request = request.bearer_auth(token);
 		}
 		let resp = request.send().await?;
-		let repo_info: JsonValue = resp.json().await?;
-		Ok(repo_info.into())
+		decode::json_into::<Repo, _>(resp, self.config.include_raw).await
 	}
 
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
 	async fn get_user_repos(&self, option: Option<ReposListOptions>) -> Result<Vec<RepoInfo>> {
-		let url = format!("{}/user/repos", API_URL);
-		let mut request = HTTP_CLIENT.get(url);
-		let mut params: HashMap<&str, String> = HashMap::new();
-		if let Some(token) = &self.token {
-			request = request.bearer_auth(token);
-		}
-
-		params.insert("sort", "pushed".to_string());
+		let url = format!("{}/user/repos", self.api_url);
+		let option = option.unwrap_or_default();
+		let params = repos_params(&option, true)?;
+		Ok(self.get_page::<Repo, _>("get_user_repos", &url, &params, option.timeout).await?.items)
+	}
 
-		if let Some(option) = option {
-			let per_page = option.per_page.unwrap_or_default().min(100);
-			params.insert("per_page", per_page.to_string());
-			let page = option.page.unwrap_or_default();
-			params.insert("page", page.to_string());
+	fn get_user_repos_stream(
+		&self,
+		option: Option<ReposListOptions>,
+		limit: Option<usize>,
+	) -> BoxStream<'_, Result<RepoInfo>> {
+		let url = format!("{}/user/repos", self.api_url);
+		let option = option.unwrap_or_default();
+		match repos_params(&option, true) {
+			Ok(params) => self.paginate::<Repo, _>(
+				"get_user_repos_stream",
+				url,
+				params,
+				option.page(),
+				limit,
+				option.timeout,
+			),
+			Err(err) => error_stream(err),
 		}
-		let resp = request.query(&params).send().await?;
-		let repo_infos: Vec<JsonValue> = resp.json().await?;
-		Ok(repo_infos.into_iter().map(|v| v.into()).collect())
 	}
 
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
 	async fn get_user_repos_with_name(
 		&self,
 		user_name: &str,
 		option: Option<ReposListOptions>,
 	) -> Result<Vec<RepoInfo>> {
-		let url = format!("{}/users/{}/repos", API_URL, user_name);
-		let mut request = HTTP_CLIENT.get(url);
-		let mut params: HashMap<&str, String> = HashMap::new();
-		if let Some(token) = &self.token {
-			request = request.bearer_auth(token);
-		}
-		params.insert("sort", "pushed".to_string());
+		let url = format!("{}/users/{}/repos", self.api_url, user_name);
+		let option = option.unwrap_or_default();
+		let params = repos_params(&option, false)?;
+		Ok(self
+			.get_page::<Repo, _>("get_user_repos_with_name", &url, &params, option.timeout)
+			.await?
+			.items)
+	}
 
-		if let Some(option) = option {
-			let per_page = option.per_page.unwrap_or_default().min(100);
-			params.insert("per_page", per_page.to_string());
-			let page = option.page.unwrap_or_default();
-			params.insert("page", page.to_string());
+	fn get_user_repos_with_name_stream<'a>(
+		&'a self,
+		user_name: &'a str,
+		option: Option<ReposListOptions>,
+		limit: Option<usize>,
+	) -> BoxStream<'a, Result<RepoInfo>> {
+		let url = format!("{}/users/{}/repos", self.api_url, user_name);
+		let option = option.unwrap_or_default();
+		match repos_params(&option, false) {
+			Ok(params) => self.paginate::<Repo, _>(
+				"get_user_repos_with_name_stream",
+				url,
+				params,
+				option.page(),
+				limit,
+				option.timeout,
+			),
+			Err(err) => error_stream(err),
 		}
-		let resp = request.query(&params).send().await?;
-		let repo_infos: Vec<JsonValue> = resp.json().await?;
-		Ok(repo_infos.into_iter().map(|v| v.into()).collect())
 	}
 
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
 	async fn get_commit_info(
 		&self,
 		repo_path: (&str, &str),
@@ -222,88 +403,53 @@ impl Client for GitHubClient {
 	) -> Result<CommitInfo> {
 		let url = format!(
 			"{}/repos/{}/{}/commits/{}",
-			API_URL,
+			self.api_url,
 			repo_path.0,
 			repo_path.1,
 			sha.unwrap_or("HEAD")
 		);
-		let mut request = HTTP_CLIENT.get(url);
-		if let Some(token) = &self.token {
+		let mut request = self.http.get(url).with_extension(Operation("get_commit_info"));
+		if let Some(token) = &self.config.token {
 			request = request.bearer_auth(token);
 		}
 		let resp = request.send().await?;
-		let mut commit_info: JsonValue = resp.json().await?;
-		let author_avatar_url = commit_info
-			.0
-			.get("author")
-			.and_then(|v| v.get("avatar_url"))
-			.and_then(|v| v.as_str())
-			.unwrap()
-			.to_string();
-		let committer_avatar_url = commit_info
-			.0
-			.get("committer")
-			.and_then(|v| v.get("avatar_url"))
-			.and_then(|v| v.as_str())
-			.unwrap()
-			.to_string();
-		if let Some(author_obj) = commit_info
-			.0
-			.get_mut("commit")
-			.and_then(|commit| commit.as_object_mut())
-			.and_then(|commit_obj| commit_obj.get_mut("author"))
-			.and_then(|author| author.as_object_mut())
-		{
-			author_obj.insert("avatar_url".to_string(), Value::String(author_avatar_url));
-		}
-
-		if let Some(committer_obj) = commit_info
-			.0
-			.get_mut("commit")
-			.and_then(|commit| commit.as_object_mut())
-			.and_then(|commit_obj| commit_obj.get_mut("committer"))
-			.and_then(|committer| committer.as_object_mut())
-		{
-			committer_obj.insert("avatar_url".to_string(), Value::String(committer_avatar_url));
-		}
-		Ok(commit_info.into())
+		decode::json_into::<Commit, _>(resp, self.config.include_raw).await
 	}
 
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
 	async fn get_commit_infos(
 		&self,
 		repo_path: (&str, &str),
 		option: Option<CommitListOptions>,
 	) -> Result<Vec<CommitInfo>> {
-		let url = format!("{}/repos/{}/{}/commits", API_URL, repo_path.0, repo_path.1);
-		let mut request = HTTP_CLIENT.get(url);
-		let mut params: HashMap<&str, String> = HashMap::new();
-		if let Some(token) = &self.token {
-			request = request.bearer_auth(token);
-		}
-
-		if let Some(option) = option {
-			let per_page = option.per_page.unwrap_or_default().min(100);
-			params.insert("per_page", per_page.to_string());
-			let page = option.page.unwrap_or_default();
-			params.insert("page", page.to_string());
-			if let Some(sha) = option.sha {
-				params.insert("sha", sha.to_string());
-			}
-			if let Some(author) = option.author {
-				params.insert("author", author.to_string());
-			}
-			if let Some(since) = option.since {
-				params.insert("since", since.to_rfc3339());
-			}
-			if let Some(until) = option.until {
-				params.insert("until", until.to_rfc3339());
-			}
-		}
-		let resp = request.query(&params).send().await?;
-		let commit_infos: Vec<JsonValue> = resp.json().await?;
-		Ok(commit_infos.into_iter().map(|v| v.into()).collect())
+		let url = format!("{}/repos/{}/{}/commits", self.api_url, repo_path.0, repo_path.1);
+		let option = option.unwrap_or_default();
+		let params = commits_params(&option);
+		Ok(self
+			.get_page::<Commit, _>("get_commit_infos", &url, &params, option.timeout)
+			.await?
+			.items)
+	}
+
+	fn get_commit_infos_stream<'a>(
+		&'a self,
+		repo_path: (&'a str, &'a str),
+		option: Option<CommitListOptions>,
+		limit: Option<usize>,
+	) -> BoxStream<'a, Result<CommitInfo>> {
+		let url = format!("{}/repos/{}/{}/commits", self.api_url, repo_path.0, repo_path.1);
+		let option = option.unwrap_or_default();
+		self.paginate::<Commit, _>(
+			"get_commit_infos_stream",
+			url,
+			commits_params(&option),
+			option.page(),
+			limit,
+			option.timeout,
+		)
 	}
 
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
 	async fn add_repo_collaborator(
 		&self,
 		repo_path: (&str, &str),
@@ -312,10 +458,10 @@ impl Client for GitHubClient {
 	) -> Result<CollaboratorResult> {
 		let url = format!(
 			"{}/repos/{}/{}/collaborators/{}",
-			API_URL, repo_path.0, repo_path.1, user_name
+			self.api_url, repo_path.0, repo_path.1, user_name
 		);
-		let mut request = HTTP_CLIENT.put(url);
-		if let Some(token) = &self.token {
+		let mut request = self.http.put(url).with_extension(Operation("add_repo_collaborator"));
+		if let Some(token) = &self.config.token {
 			request = request.bearer_auth(token);
 		}
 		let permission = match permission {
@@ -335,7 +481,835 @@ impl Client for GitHubClient {
 			.body(body.to_string())
 			.send()
 			.await?;
-		let collaborator_result: JsonValue = resp.json().await?;
+		// 用户已是协作者时平台返回 204, 没有响应体
+		if resp.status() == StatusCode::NO_CONTENT {
+			return Ok(CollaboratorResult {
+				login: user_name.to_string(),
+				avatar_url: self.get_user_avatar_url(user_name).await?,
+			});
+		}
+		let collaborator_result: Invitation = decode::json(resp).await?;
 		Ok(collaborator_result.into())
 	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn get_branches(
+		&self,
+		repo_path: (&str, &str),
+		option: Option<BranchListOptions>,
+	) -> Result<Vec<BranchInfo>> {
+		let url = format!("{}/repos/{}/{}/branches", self.api_url, repo_path.0, repo_path.1);
+		let option = option.unwrap_or_default();
+		let params = branches_params(&option);
+		Ok(self.get_page::<Branch, _>("get_branches", &url, &params, option.timeout).await?.items)
+	}
+
+	fn get_branches_stream<'a>(
+		&'a self,
+		repo_path: (&'a str, &'a str),
+		option: Option<BranchListOptions>,
+		limit: Option<usize>,
+	) -> BoxStream<'a, Result<BranchInfo>> {
+		let url = format!("{}/repos/{}/{}/branches", self.api_url, repo_path.0, repo_path.1);
+		let option = option.unwrap_or_default();
+		let params = branches_params(&option);
+		self.paginate::<Branch, _>(
+			"get_branches_stream",
+			url,
+			params,
+			option.page(),
+			limit,
+			option.timeout,
+		)
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn get_branch(&self, repo_path: (&str, &str), branch: &str) -> Result<BranchInfo> {
+		let url =
+			format!("{}/repos/{}/{}/branches/{}", self.api_url, repo_path.0, repo_path.1, branch);
+		let mut request = self.http.get(url).with_extension(Operation("get_branch"));
+		if let Some(token) = &self.config.token {
+			request = request.bearer_auth(token);
+		}
+		let resp = request.send().await?;
+		decode::json_into::<Branch, _>(resp, self.config.include_raw).await
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn create_branch(
+		&self,
+		repo_path: (&str, &str),
+		branch: &str,
+		from: &str,
+	) -> Result<BranchInfo> {
+		let sha = self.resolve_sha(repo_path, from, "create_branch").await?;
+		let ref_name = format!("refs/heads/{}", branch);
+		let resp = self.create_ref(repo_path, &ref_name, &sha, "create_branch").await?;
+		decode::json_into::<GitRef, _>(resp, self.config.include_raw).await
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn delete_branch(&self, repo_path: (&str, &str), branch: &str) -> Result<()> {
+		let url = format!(
+			"{}/repos/{}/{}/git/refs/heads/{}",
+			self.api_url, repo_path.0, repo_path.1, branch
+		);
+		let mut request = self.http.delete(url).with_extension(Operation("delete_branch"));
+		if let Some(token) = &self.config.token {
+			request = request.bearer_auth(token);
+		}
+		request.send().await?;
+		Ok(())
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn get_tags(
+		&self,
+		repo_path: (&str, &str),
+		option: Option<TagListOptions>,
+	) -> Result<Vec<TagInfo>> {
+		let url = format!("{}/repos/{}/{}/tags", self.api_url, repo_path.0, repo_path.1);
+		let option = option.unwrap_or_default();
+		let params = tags_params(&option);
+		Ok(self.get_page::<Tag, _>("get_tags", &url, &params, option.timeout).await?.items)
+	}
+
+	fn get_tags_stream<'a>(
+		&'a self,
+		repo_path: (&'a str, &'a str),
+		option: Option<TagListOptions>,
+		limit: Option<usize>,
+	) -> BoxStream<'a, Result<TagInfo>> {
+		let url = format!("{}/repos/{}/{}/tags", self.api_url, repo_path.0, repo_path.1);
+		let option = option.unwrap_or_default();
+		self.paginate::<Tag, _>(
+			"get_tags_stream",
+			url,
+			tags_params(&option),
+			option.page(),
+			limit,
+			option.timeout,
+		)
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn get_tag(&self, repo_path: (&str, &str), tag: &str) -> Result<TagInfo> {
+		let url =
+			format!("{}/repos/{}/{}/git/ref/tags/{}", self.api_url, repo_path.0, repo_path.1, tag);
+		let mut request = self.http.get(url).with_extension(Operation("get_tag"));
+		if let Some(token) = &self.config.token {
+			request = request.bearer_auth(token);
+		}
+		let resp = request.send().await?;
+		let (git_ref, raw) = decode::json_with_raw::<GitRef>(resp, self.config.include_raw).await?;
+		// 轻量标签的引用直接指向提交, 附注标签的引用指向标签对象
+		if git_ref.object.object_type.as_deref() != Some("tag") {
+			let mut tag_info = TagInfo::from(git_ref);
+			tag_info.raw = raw;
+			return Ok(tag_info);
+		}
+		let url = format!(
+			"{}/repos/{}/{}/git/tags/{}",
+			self.api_url, repo_path.0, repo_path.1, git_ref.object.sha
+		);
+		let mut request = self.http.get(url).with_extension(Operation("get_tag"));
+		if let Some(token) = &self.config.token {
+			request = request.bearer_auth(token);
+		}
+		let resp = request.send().await?;
+		decode::json_into::<GitTag, _>(resp, self.config.include_raw).await
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn create_tag(
+		&self,
+		repo_path: (&str, &str),
+		tag: &str,
+		target: &str,
+		message: Option<&str>,
+	) -> Result<TagInfo> {
+		let sha = self.resolve_sha(repo_path, target, "create_tag").await?;
+		let ref_name = format!("refs/tags/{}", tag);
+		let Some(message) = message else {
+			let resp = self.create_ref(repo_path, &ref_name, &sha, "create_tag").await?;
+			return decode::json_into::<GitRef, _>(resp, self.config.include_raw).await;
+		};
+		let url = format!("{}/repos/{}/{}/git/tags", self.api_url, repo_path.0, repo_path.1);
+		let mut request = self.http.post(url).with_extension(Operation("create_tag"));
+		if let Some(token) = &self.config.token {
+			request = request.bearer_auth(token);
+		}
+		let body = serde_json::json!({
+			"tag": tag,
+			"message": message,
+			"object": sha,
+			"type": "commit",
+		});
+		let resp = request
+			.header(header::CONTENT_TYPE, "application/json")
+			.body(body.to_string())
+			.send()
+			.await?;
+		let (git_tag, raw) = decode::json_with_raw::<GitTag>(resp, self.config.include_raw).await?;
+		// 标签对象创建后还需要创建指向它的引用
+		self.create_ref(repo_path, &ref_name, &git_tag.sha, "create_tag").await?;
+		let mut tag_info = TagInfo::from(git_tag);
+		tag_info.raw = raw;
+		Ok(tag_info)
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn delete_tag(&self, repo_path: (&str, &str), tag: &str) -> Result<()> {
+		let url =
+			format!("{}/repos/{}/{}/git/refs/tags/{}", self.api_url, repo_path.0, repo_path.1, tag);
+		let mut request = self.http.delete(url).with_extension(Operation("delete_tag"));
+		if let Some(token) = &self.config.token {
+			request = request.bearer_auth(token);
+		}
+		request.send().await?;
+		Ok(())
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn get_releases(
+		&self,
+		repo_path: (&str, &str),
+		option: Option<ReleaseListOptions>,
+	) -> Result<Vec<ReleaseInfo>> {
+		let url = format!("{}/repos/{}/{}/releases", self.api_url, repo_path.0, repo_path.1);
+		let option = option.unwrap_or_default();
+		let params = releases_params(&option);
+		Ok(self.get_page::<Release, _>("get_releases", &url, &params, option.timeout).await?.items)
+	}
+
+	fn get_releases_stream<'a>(
+		&'a self,
+		repo_path: (&'a str, &'a str),
+		option: Option<ReleaseListOptions>,
+		limit: Option<usize>,
+	) -> BoxStream<'a, Result<ReleaseInfo>> {
+		let url = format!("{}/repos/{}/{}/releases", self.api_url, repo_path.0, repo_path.1);
+		let option = option.unwrap_or_default();
+		self.paginate::<Release, _>(
+			"get_releases_stream",
+			url,
+			releases_params(&option),
+			option.page(),
+			limit,
+			option.timeout,
+		)
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn get_latest_release(&self, repo_path: (&str, &str)) -> Result<ReleaseInfo> {
+		let url = format!("{}/repos/{}/{}/releases/latest", self.api_url, repo_path.0, repo_path.1);
+		let mut request = self.http.get(url).with_extension(Operation("get_latest_release"));
+		if let Some(token) = &self.config.token {
+			request = request.bearer_auth(token);
+		}
+		let resp = request.send().await?;
+		decode::json_into::<Release, _>(resp, self.config.include_raw).await
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn get_release_by_tag(&self, repo_path: (&str, &str), tag: &str) -> Result<ReleaseInfo> {
+		let url =
+			format!("{}/repos/{}/{}/releases/tags/{}", self.api_url, repo_path.0, repo_path.1, tag);
+		let mut request = self.http.get(url).with_extension(Operation("get_release_by_tag"));
+		if let Some(token) = &self.config.token {
+			request = request.bearer_auth(token);
+		}
+		let resp = request.send().await?;
+		decode::json_into::<Release, _>(resp, self.config.include_raw).await
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn create_release(
+		&self,
+		repo_path: (&str, &str),
+		option: ReleaseOptions,
+	) -> Result<ReleaseInfo> {
+		let url = format!("{}/repos/{}/{}/releases", self.api_url, repo_path.0, repo_path.1);
+		let mut request = self.http.post(url).with_extension(Operation("create_release"));
+		if let Some(token) = &self.config.token {
+			request = request.bearer_auth(token);
+		}
+		let resp = request
+			.header(header::CONTENT_TYPE, "application/json")
+			.body(release_body(&option.into()).to_string())
+			.send()
+			.await?;
+		decode::json_into::<Release, _>(resp, self.config.include_raw).await
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn update_release(
+		&self,
+		repo_path: (&str, &str),
+		release_id: &str,
+		option: ReleaseUpdateOptions,
+	) -> Result<ReleaseInfo> {
+		let url = format!(
+			"{}/repos/{}/{}/releases/{}",
+			self.api_url, repo_path.0, repo_path.1, release_id
+		);
+		let mut request = self.http.patch(url).with_extension(Operation("update_release"));
+		if let Some(token) = &self.config.token {
+			request = request.bearer_auth(token);
+		}
+		let resp = request
+			.header(header::CONTENT_TYPE, "application/json")
+			.body(release_body(&option).to_string())
+			.send()
+			.await?;
+		decode::json_into::<Release, _>(resp, self.config.include_raw).await
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn delete_release(&self, repo_path: (&str, &str), release_id: &str) -> Result<()> {
+		let url = format!(
+			"{}/repos/{}/{}/releases/{}",
+			self.api_url, repo_path.0, repo_path.1, release_id
+		);
+		let mut request = self.http.delete(url).with_extension(Operation("delete_release"));
+		if let Some(token) = &self.config.token {
+			request = request.bearer_auth(token);
+		}
+		request.send().await?;
+		Ok(())
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn upload_release_asset(
+		&self,
+		repo_path: (&str, &str),
+		release_id: &str,
+		asset: AssetUpload,
+	) -> Result<ReleaseAsset> {
+		// 上传地址与接口地址不同, 从发布信息中获取
+		let url = format!(
+			"{}/repos/{}/{}/releases/{}",
+			self.api_url, repo_path.0, repo_path.1, release_id
+		);
+		let mut request = self.http.get(url).with_extension(Operation("upload_release_asset"));
+		if let Some(token) = &self.config.token {
+			request = request.bearer_auth(token);
+		}
+		let release: Release = decode::json(request.send().await?).await?;
+		let upload_url = release.upload_url.split('{').next().unwrap_or_default().to_string();
+
+		let mut request =
+			self.http.post(upload_url).with_extension(Operation("upload_release_asset"));
+		if let Some(token) = &self.config.token {
+			request = request.bearer_auth(token);
+		}
+		let resp = request
+			.query(&[("name", asset.name.as_str())])
+			.header(header::CONTENT_TYPE, asset.mime())
+			.header(header::CONTENT_LENGTH, asset.size)
+			.body(asset.body)
+			.send()
+			.await?;
+		let asset: Asset = decode::json(resp).await?;
+		Ok(asset.into())
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn download_release_asset(
+		&self,
+		repo_path: (&str, &str),
+		_release_id: &str,
+		asset_id: &str,
+	) -> Result<AssetStream> {
+		let url = format!(
+			"{}/repos/{}/{}/releases/assets/{}",
+			self.api_url, repo_path.0, repo_path.1, asset_id
+		);
+		let mut request = self
+			.http
+			.get(url)
+			.with_extension(Operation("download_release_asset"))
+			.header(header::ACCEPT, "application/octet-stream");
+		if let Some(token) = &self.config.token {
+			request = request.bearer_auth(token);
+		}
+		let resp = request.send().await?;
+		Ok(decode::bytes_stream(resp))
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn delete_release_asset(
+		&self,
+		repo_path: (&str, &str),
+		_release_id: &str,
+		asset_id: &str,
+	) -> Result<()> {
+		let url = format!(
+			"{}/repos/{}/{}/releases/assets/{}",
+			self.api_url, repo_path.0, repo_path.1, asset_id
+		);
+		let mut request = self.http.delete(url).with_extension(Operation("delete_release_asset"));
+		if let Some(token) = &self.config.token {
+			request = request.bearer_auth(token);
+		}
+		request.send().await?;
+		Ok(())
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn get_issues(
+		&self,
+		repo_path: (&str, &str),
+		option: Option<IssueListOptions>,
+	) -> Result<Vec<IssueInfo>> {
+		let url = format!("{}/repos/{}/{}/issues", self.api_url, repo_path.0, repo_path.1);
+		let option = option.unwrap_or_default();
+		let params = issues_params(&option);
+		let issues = self
+			.get_page::<Issue, IssueInfo>("get_issues", &url, &params, option.timeout)
+			.await?
+			.items;
+		// Issue 列表接口同时返回 PR, 与其他平台保持一致只保留 Issue
+		Ok(issues.into_iter().filter(|issue| !issue.pull_request).collect())
+	}
+
+	fn get_issues_stream<'a>(
+		&'a self,
+		repo_path: (&'a str, &'a str),
+		option: Option<IssueListOptions>,
+		limit: Option<usize>,
+	) -> BoxStream<'a, Result<IssueInfo>> {
+		let url = format!("{}/repos/{}/{}/issues", self.api_url, repo_path.0, repo_path.1);
+		let option = option.unwrap_or_default();
+		let issues = self
+			.paginate::<Issue, IssueInfo>(
+				"get_issues_stream",
+				url,
+				issues_params(&option),
+				option.page(),
+				None,
+				option.timeout,
+			)
+			.try_filter(|issue| future::ready(!issue.pull_request));
+		match limit {
+			Some(limit) => issues.take(limit).boxed(),
+			None => issues.boxed(),
+		}
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn get_issue(&self, repo_path: (&str, &str), number: &str) -> Result<IssueInfo> {
+		let url =
+			format!("{}/repos/{}/{}/issues/{}", self.api_url, repo_path.0, repo_path.1, number);
+		let mut request = self.http.get(url).with_extension(Operation("get_issue"));
+		if let Some(token) = &self.config.token {
+			request = request.bearer_auth(token);
+		}
+		let resp = request.send().await?;
+		decode::json_into::<Issue, _>(resp, self.config.include_raw).await
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn create_issue(
+		&self,
+		repo_path: (&str, &str),
+		option: IssueOptions,
+	) -> Result<IssueInfo> {
+		let url = format!("{}/repos/{}/{}/issues", self.api_url, repo_path.0, repo_path.1);
+		let mut request = self.http.post(url).with_extension(Operation("create_issue"));
+		if let Some(token) = &self.config.token {
+			request = request.bearer_auth(token);
+		}
+		let resp = request
+			.header(header::CONTENT_TYPE, "application/json")
+			.body(issue_body(&option.into()).to_string())
+			.send()
+			.await?;
+		decode::json_into::<Issue, _>(resp, self.config.include_raw).await
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn update_issue(
+		&self,
+		repo_path: (&str, &str),
+		number: &str,
+		option: IssueUpdateOptions,
+	) -> Result<IssueInfo> {
+		self.patch_issue(repo_path, number, issue_body(&option), "update_issue").await
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn close_issue(&self, repo_path: (&str, &str), number: &str) -> Result<IssueInfo> {
+		let body = serde_json::json!({ "state": "closed" });
+		self.patch_issue(repo_path, number, body, "close_issue").await
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn lock_issue(&self, repo_path: (&str, &str), number: &str) -> Result<()> {
+		let url = format!(
+			"{}/repos/{}/{}/issues/{}/lock",
+			self.api_url, repo_path.0, repo_path.1, number
+		);
+		let mut request = self.http.put(url).with_extension(Operation("lock_issue"));
+		if let Some(token) = &self.config.token {
+			request = request.bearer_auth(token);
+		}
+		request.header(header::CONTENT_LENGTH, 0).send().await?;
+		Ok(())
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn unlock_issue(&self, repo_path: (&str, &str), number: &str) -> Result<()> {
+		let url = format!(
+			"{}/repos/{}/{}/issues/{}/lock",
+			self.api_url, repo_path.0, repo_path.1, number
+		);
+		let mut request = self.http.delete(url).with_extension(Operation("unlock_issue"));
+		if let Some(token) = &self.config.token {
+			request = request.bearer_auth(token);
+		}
+		request.send().await?;
+		Ok(())
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn get_comments(
+		&self,
+		repo_path: (&str, &str),
+		_target: CommentTarget,
+		number: &str,
+		option: Option<CommentListOptions>,
+	) -> Result<Vec<CommentInfo>> {
+		// PR 的普通评论与 Issue 评论使用同一组接口
+		let url = format!(
+			"{}/repos/{}/{}/issues/{}/comments",
+			self.api_url, repo_path.0, repo_path.1, number
+		);
+		let option = option.unwrap_or_default();
+		let params = comments_params(&option);
+		Ok(self.get_page::<Comment, _>("get_comments", &url, &params, option.timeout).await?.items)
+	}
+
+	fn get_comments_stream<'a>(
+		&'a self,
+		repo_path: (&'a str, &'a str),
+		_target: CommentTarget,
+		number: &'a str,
+		option: Option<CommentListOptions>,
+		limit: Option<usize>,
+	) -> BoxStream<'a, Result<CommentInfo>> {
+		let url = format!(
+			"{}/repos/{}/{}/issues/{}/comments",
+			self.api_url, repo_path.0, repo_path.1, number
+		);
+		let option = option.unwrap_or_default();
+		self.paginate::<Comment, _>(
+			"get_comments_stream",
+			url,
+			comments_params(&option),
+			option.page(),
+			limit,
+			option.timeout,
+		)
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn get_review_comments(
+		&self,
+		repo_path: (&str, &str),
+		number: &str,
+		option: Option<CommentListOptions>,
+	) -> Result<Vec<CommentInfo>> {
+		let url = format!(
+			"{}/repos/{}/{}/pulls/{}/comments",
+			self.api_url, repo_path.0, repo_path.1, number
+		);
+		let option = option.unwrap_or_default();
+		let params = comments_params(&option);
+		Ok(self
+			.get_page::<Comment, _>("get_review_comments", &url, &params, option.timeout)
+			.await?
+			.items)
+	}
+
+	fn get_review_comments_stream<'a>(
+		&'a self,
+		repo_path: (&'a str, &'a str),
+		number: &'a str,
+		option: Option<CommentListOptions>,
+		limit: Option<usize>,
+	) -> BoxStream<'a, Result<CommentInfo>> {
+		let url = format!(
+			"{}/repos/{}/{}/pulls/{}/comments",
+			self.api_url, repo_path.0, repo_path.1, number
+		);
+		let option = option.unwrap_or_default();
+		self.paginate::<Comment, _>(
+			"get_review_comments_stream",
+			url,
+			comments_params(&option),
+			option.page(),
+			limit,
+			option.timeout,
+		)
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn create_comment(
+		&self,
+		repo_path: (&str, &str),
+		_target: CommentTarget,
+		number: &str,
+		body: &str,
+	) -> Result<CommentInfo> {
+		let url = format!(
+			"{}/repos/{}/{}/issues/{}/comments",
+			self.api_url, repo_path.0, repo_path.1, number
+		);
+		let mut request = self.http.post(url).with_extension(Operation("create_comment"));
+		if let Some(token) = &self.config.token {
+			request = request.bearer_auth(token);
+		}
+		let resp = request
+			.header(header::CONTENT_TYPE, "application/json")
+			.body(serde_json::json!({ "body": body }).to_string())
+			.send()
+			.await?;
+		decode::json_into::<Comment, _>(resp, self.config.include_raw).await
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn update_comment(
+		&self,
+		repo_path: (&str, &str),
+		_target: CommentTarget,
+		_number: &str,
+		comment_id: &str,
+		body: &str,
+	) -> Result<CommentInfo> {
+		let url = format!(
+			"{}/repos/{}/{}/issues/comments/{}",
+			self.api_url, repo_path.0, repo_path.1, comment_id
+		);
+		let mut request = self.http.patch(url).with_extension(Operation("update_comment"));
+		if let Some(token) = &self.config.token {
+			request = request.bearer_auth(token);
+		}
+		let resp = request
+			.header(header::CONTENT_TYPE, "application/json")
+			.body(serde_json::json!({ "body": body }).to_string())
+			.send()
+			.await?;
+		decode::json_into::<Comment, _>(resp, self.config.include_raw).await
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn delete_comment(
+		&self,
+		repo_path: (&str, &str),
+		_target: CommentTarget,
+		_number: &str,
+		comment_id: &str,
+	) -> Result<()> {
+		let url = format!(
+			"{}/repos/{}/{}/issues/comments/{}",
+			self.api_url, repo_path.0, repo_path.1, comment_id
+		);
+		let mut request = self.http.delete(url).with_extension(Operation("delete_comment"));
+		if let Some(token) = &self.config.token {
+			request = request.bearer_auth(token);
+		}
+		request.send().await?;
+		Ok(())
+	}
+
+	#[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(platform = PLATFORM), err))]
+	async fn get_rate_limit(&self) -> Result<RateLimitInfo> {
+		let url = format!("{}/rate_limit", self.api_url);
+		let mut request = self.http.get(url).with_extension(Operation("get_rate_limit"));
+		if let Some(token) = &self.config.token {
+			request = request.bearer_auth(token);
+		}
+		let resp = request.send().await?;
+		let rate_limit: RateLimit = decode::json(resp).await?;
+		Ok(rate_limit.into())
+	}
+}
+
+fn org_repos_params(option: &OrgRepoListOptions) -> HashMap<&'static str, String> {
+	let mut params = HashMap::new();
+	params.insert("per_page", option.per_page().to_string());
+	params.insert("page", option.page().to_string());
+	if let Some(repo_type) = option.repo_type {
+		params.insert("type", repo_type.to_string());
+	}
+	if let Some(sort) = option.sort {
+		params.insert("sort", sort.to_string());
+	}
+	if let Some(direction) = option.direction {
+		params.insert("direction", direction.to_string());
+	}
+	params
+}
+
+fn repos_params(
+	option: &ReposListOptions,
+	authenticated: bool,
+) -> Result<HashMap<&'static str, String>> {
+	let mut params = HashMap::new();
+	params.insert("sort", option.sort.unwrap_or(RepoSort::Pushed).to_string());
+	params.insert("per_page", option.per_page().to_string());
+	params.insert("page", option.page().to_string());
+	if let Some(direction) = option.direction {
+		params.insert("direction", direction.to_string());
+	}
+	if let Some(visibility) = option.visibility {
+		if !authenticated {
+			return Err(unsupported("visibility".to_string()));
+		}
+		params.insert("visibility", visibility.to_string());
+	}
+	if let Some(affiliation) = option.affiliation {
+		if !authenticated {
+			return Err(unsupported("affiliation".to_string()));
+		}
+		params.insert("affiliation", affiliation.to_string());
+	}
+	if let Some(repo_type) = option.repo_type {
+		if option.visibility.is_some() || option.affiliation.is_some() {
+			return Err(unsupported("type with visibility or affiliation".to_string()));
+		}
+		if !authenticated && matches!(repo_type, RepoType::Public | RepoType::Private) {
+			return Err(unsupported(format!("type={repo_type}")));
+		}
+		params.insert("type", repo_type.to_string());
+	}
+	if option.search.is_some() {
+		return Err(unsupported("search".to_string()));
+	}
+	Ok(params)
+}
+
+fn unsupported(option: String) -> Error {
+	Error::Unsupported { platform: PLATFORM, option }
+}
+
+fn commits_params(option: &CommitListOptions) -> HashMap<&'static str, String> {
+	let mut params = HashMap::new();
+	params.insert("per_page", option.per_page().to_string());
+	params.insert("page", option.page().to_string());
+	if let Some(sha) = &option.sha {
+		params.insert("sha", sha.to_string());
+	}
+	if let Some(author) = &option.author {
+		params.insert("author", author.to_string());
+	}
+	if let Some(since) = option.since {
+		params.insert("since", since.to_rfc3339());
+	}
+	if let Some(until) = option.until {
+		params.insert("until", until.to_rfc3339());
+	}
+	params
+}
+
+fn branches_params(option: &BranchListOptions) -> HashMap<&'static str, String> {
+	let mut params = HashMap::new();
+	params.insert("per_page", option.per_page().to_string());
+	params.insert("page", option.page().to_string());
+	params
+}
+
+fn tags_params(option: &TagListOptions) -> HashMap<&'static str, String> {
+	let mut params = HashMap::new();
+	params.insert("per_page", option.per_page().to_string());
+	params.insert("page", option.page().to_string());
+	params
+}
+
+fn releases_params(option: &ReleaseListOptions) -> HashMap<&'static str, String> {
+	let mut params = HashMap::new();
+	params.insert("per_page", option.per_page().to_string());
+	params.insert("page", option.page().to_string());
+	params
+}
+
+/// 创建和更新发布的请求体, 未设置的字段不会发送
+fn release_body(option: &ReleaseUpdateOptions) -> serde_json::Value {
+	let mut body = serde_json::json!({});
+	if let Some(tag_name) = &option.tag_name {
+		body["tag_name"] = tag_name.as_str().into();
+	}
+	if let Some(target_commitish) = &option.target_commitish {
+		body["target_commitish"] = target_commitish.as_str().into();
+	}
+	if let Some(name) = &option.name {
+		body["name"] = name.as_str().into();
+	}
+	if let Some(release_body) = &option.body {
+		body["body"] = release_body.as_str().into();
+	}
+	if let Some(draft) = option.draft {
+		body["draft"] = draft.into();
+	}
+	if let Some(prerelease) = option.prerelease {
+		body["prerelease"] = prerelease.into();
+	}
+	body
+}
+
+fn issues_params(option: &IssueListOptions) -> HashMap<&'static str, String> {
+	let mut params = HashMap::new();
+	params.insert("per_page", option.per_page().to_string());
+	params.insert("page", option.page().to_string());
+	if let Some(state) = option.state {
+		params.insert("state", state.to_string());
+	}
+	if let Some(labels) = &option.labels {
+		params.insert("labels", labels.join(","));
+	}
+	if let Some(assignee) = &option.assignee {
+		params.insert("assignee", assignee.to_string());
+	}
+	if let Some(creator) = &option.creator {
+		params.insert("creator", creator.to_string());
+	}
+	if let Some(since) = option.since {
+		params.insert("since", since.to_rfc3339());
+	}
+	if let Some(sort) = option.sort {
+		params.insert("sort", sort.to_string());
+	}
+	if let Some(direction) = option.direction {
+		params.insert("direction", direction.to_string());
+	}
+	params
+}
+
+/// 创建和更新 Issue 的请求体, 未设置的字段不会发送
+fn issue_body(option: &IssueUpdateOptions) -> serde_json::Value {
+	let mut body = serde_json::json!({});
+	if let Some(title) = &option.title {
+		body["title"] = title.as_str().into();
+	}
+	if let Some(issue_body) = &option.body {
+		body["body"] = issue_body.as_str().into();
+	}
+	if let Some(labels) = &option.labels {
+		body["labels"] = labels.clone().into();
+	}
+	if let Some(assignees) = &option.assignees {
+		body["assignees"] = assignees.clone().into();
+	}
+	if let Some(milestone) = option.milestone {
+		body["milestone"] = milestone.into();
+	}
+	body
+}
+
+fn comments_params(option: &CommentListOptions) -> HashMap<&'static str, String> {
+	let mut params = HashMap::new();
+	params.insert("per_page", option.per_page().to_string());
+	params.insert("page", option.page().to_string());
+	params
 }
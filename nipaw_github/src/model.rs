@@ -0,0 +1,269 @@
+//! GitHub API 的原始响应模型
+//!
+//! 字段与 GitHub REST API 保持一致, 客户端返回的统一类型均由这些模型转换而来
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// 用户信息, 对应 `/user` 和 `/users/{username}`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct User {
+	pub id: u64,
+	pub login: String,
+	pub name: Option<String>,
+	pub email: Option<String>,
+	pub avatar_url: String,
+	pub followers: u64,
+	pub following: u64,
+	pub public_repos: u64,
+}
+
+/// 仓库、提交等数据中引用的账号
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Account {
+	pub login: String,
+	pub avatar_url: String,
+}
+
+/// 仓库信息, 对应 `/repos/{owner}/{repo}` 及各仓库列表接口
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Repo {
+	pub id: u64,
+	pub owner: Account,
+	pub name: String,
+	pub full_name: String,
+	pub description: Option<String>,
+	pub visibility: Option<String>,
+	pub fork: Option<bool>,
+	pub forks_count: Option<u64>,
+	pub language: Option<String>,
+	pub stargazers_count: Option<u64>,
+	pub default_branch: String,
+	pub created_at: DateTime<Utc>,
+	pub updated_at: DateTime<Utc>,
+	pub pushed_at: DateTime<Utc>,
+}
+
+/// 组织信息, 对应 `/orgs/{org}`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Org {
+	pub id: u64,
+	pub login: String,
+	pub name: Option<String>,
+	pub email: Option<String>,
+	pub avatar_url: String,
+	pub description: Option<String>,
+	pub follow_count: Option<u64>,
+}
+
+/// 提交信息, 对应 `/repos/{owner}/{repo}/commits/{ref}` 及提交列表接口
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Commit {
+	pub sha: String,
+	pub commit: CommitDetail,
+	/// 提交作者对应的 GitHub 账号, 作者邮箱未关联账号时为空
+	pub author: Option<Account>,
+	/// 提交者对应的 GitHub 账号, 提交者邮箱未关联账号时为空
+	pub committer: Option<Account>,
+	/// 提交统计, 仅获取单个提交时返回
+	pub stats: Option<CommitStats>,
+}
+
+/// Git 提交数据
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CommitDetail {
+	pub author: CommitSignature,
+	pub committer: CommitSignature,
+	pub message: String,
+}
+
+/// Git 提交签名
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CommitSignature {
+	pub name: String,
+	pub email: Option<String>,
+	pub date: DateTime<Utc>,
+}
+
+/// 提交统计
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CommitStats {
+	pub total: Option<u64>,
+	pub additions: Option<u64>,
+	pub deletions: Option<u64>,
+}
+
+/// 仓库协作邀请, 对应 `PUT /repos/{owner}/{repo}/collaborators/{username}`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Invitation {
+	pub inviter: Account,
+}
+
+/// 错误响应体, 对应所有非成功响应
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ErrorBody {
+	pub message: Option<String>,
+	pub documentation_url: Option<String>,
+	/// 校验失败的字段, 仅 422 响应返回
+	#[serde(default)]
+	pub errors: Vec<ErrorDetail>,
+}
+
+/// 校验失败的字段详情
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ErrorDetail {
+	pub resource: Option<String>,
+	pub field: Option<String>,
+	pub code: Option<String>,
+}
+
+/// 限流状态, 对应 `/rate_limit`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RateLimit {
+	pub rate: RateLimitRate,
+}
+
+/// 核心接口的限流状态
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RateLimitRate {
+	pub limit: u64,
+	pub remaining: u64,
+	/// 重置时间的 Unix 时间戳
+	pub reset: i64,
+}
+
+/// 分支信息, 对应 `/repos/{owner}/{repo}/branches` 和 `/repos/{owner}/{repo}/branches/{branch}`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Branch {
+	pub name: String,
+	pub commit: BranchCommit,
+	pub protected: Option<bool>,
+}
+
+/// 分支指向的提交
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BranchCommit {
+	pub sha: String,
+}
+
+/// Git 引用, 对应 `POST /repos/{owner}/{repo}/git/refs`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct GitRef {
+	#[serde(rename = "ref")]
+	pub ref_name: String,
+	pub object: GitObject,
+}
+
+/// Git 引用指向的对象
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct GitObject {
+	pub sha: String,
+	#[serde(rename = "type")]
+	pub object_type: Option<String>,
+}
+
+/// 标签, 对应 `/repos/{owner}/{repo}/tags`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Tag {
+	pub name: String,
+	pub commit: TagCommit,
+}
+
+/// 标签指向的提交
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TagCommit {
+	pub sha: String,
+}
+
+/// 附注标签对象, 对应 `/repos/{owner}/{repo}/git/tags/{sha}`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct GitTag {
+	pub tag: String,
+	/// 标签对象自身的SHA
+	pub sha: String,
+	pub message: String,
+	pub tagger: CommitSignature,
+	pub object: GitObject,
+}
+
+/// 发布信息, 对应 `/repos/{owner}/{repo}/releases` 及单个发布接口
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Release {
+	pub id: u64,
+	pub tag_name: String,
+	pub target_commitish: Option<String>,
+	pub name: Option<String>,
+	pub body: Option<String>,
+	pub draft: bool,
+	pub prerelease: bool,
+	pub author: Option<Account>,
+	pub created_at: Option<DateTime<Utc>>,
+	pub published_at: Option<DateTime<Utc>>,
+	/// 附件上传地址模板, 如 `https://uploads.github.com/repos/{owner}/{repo}/releases/{id}/assets{?name,label}`
+	pub upload_url: String,
+	#[serde(default)]
+	pub assets: Vec<Asset>,
+}
+
+/// 发布附件, 对应 `/repos/{owner}/{repo}/releases/assets/{asset_id}`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Asset {
+	pub id: u64,
+	pub name: String,
+	pub size: u64,
+	pub content_type: Option<String>,
+	pub browser_download_url: String,
+	pub download_count: u64,
+}
+
+/// Issue 信息, 对应 `/repos/{owner}/{repo}/issues` 及单个 Issue 接口
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Issue {
+	pub number: u64,
+	pub title: String,
+	pub body: Option<String>,
+	pub state: String,
+	#[serde(default)]
+	pub labels: Vec<Label>,
+	#[serde(default)]
+	pub assignees: Vec<Account>,
+	pub milestone: Option<Milestone>,
+	pub user: Option<Account>,
+	/// Issue 列表中的 PR 才有此字段
+	pub pull_request: Option<PullRequestRef>,
+	pub created_at: DateTime<Utc>,
+	pub updated_at: DateTime<Utc>,
+	pub closed_at: Option<DateTime<Utc>>,
+}
+
+/// 标签
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Label {
+	pub name: String,
+}
+
+/// 里程碑
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Milestone {
+	pub title: String,
+}
+
+/// Issue 对应的 PR
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PullRequestRef {
+	pub url: String,
+}
+
+/// 评论, 对应 Issue 评论和 PR 代码评论接口
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Comment {
+	pub id: u64,
+	pub body: String,
+	pub user: Option<Account>,
+	/// 评论所在的文件路径, 仅 PR 代码评论返回
+	pub path: Option<String>,
+	/// 评论所在的行号, 代码已变更的过期评论为空
+	pub line: Option<u64>,
+	pub created_at: DateTime<Utc>,
+	pub updated_at: DateTime<Utc>,
+}
@@ -1,6 +1,7 @@
 use chrono::{DateTime, Utc};
 use napi_derive::napi;
 use serde::Deserialize;
+use std::time::Duration;
 
 #[derive(Debug, Deserialize)]
 #[napi(object)]
@@ -9,11 +10,106 @@ pub struct ReposListOptions {
 	pub per_page: Option<u32>,
 	/// 页码，默认 1
 	pub page: Option<u32>,
+	/// 筛选仓库可见性，仅获取当前用户仓库时可用
+	pub visibility: Option<VisibilityFilter>,
+	/// 筛选与用户的关系
+	pub affiliation: Option<Affiliation>,
+	/// 筛选仓库类型，不能与 visibility 或 affiliation 同时使用
+	#[napi(js_name = "type")]
+	#[serde(rename = "type")]
+	pub repo_type: Option<RepoType>,
+	/// 排序字段
+	pub sort: Option<RepoSort>,
+	/// 排序方向
+	pub direction: Option<Direction>,
+	/// 按仓库名称搜索
+	pub search: Option<String>,
+	/// 本次调用每个请求的超时毫秒数
+	pub timeout: Option<u32>,
 }
 
 impl From<ReposListOptions> for nipaw_core::option::ReposListOptions {
 	fn from(value: ReposListOptions) -> Self {
-		nipaw_core::option::ReposListOptions { per_page: value.per_page, page: value.page }
+		nipaw_core::option::ReposListOptions {
+			per_page: value.per_page,
+			page: value.page,
+			visibility: value.visibility.map(Into::into),
+			affiliation: value.affiliation.map(Into::into),
+			repo_type: value.repo_type.map(Into::into),
+			sort: value.sort.map(Into::into),
+			direction: value.direction.map(Into::into),
+			search: value.search,
+			timeout: value.timeout.map(|timeout| Duration::from_millis(timeout.into())),
+		}
+	}
+}
+
+#[derive(Debug, Deserialize)]
+#[napi(string_enum)]
+pub enum VisibilityFilter {
+	/// 全部仓库
+	All,
+	/// 公开仓库
+	Public,
+	/// 私有仓库
+	Private,
+}
+
+impl From<VisibilityFilter> for nipaw_core::option::VisibilityFilter {
+	fn from(value: VisibilityFilter) -> Self {
+		match value {
+			VisibilityFilter::All => nipaw_core::option::VisibilityFilter::All,
+			VisibilityFilter::Public => nipaw_core::option::VisibilityFilter::Public,
+			VisibilityFilter::Private => nipaw_core::option::VisibilityFilter::Private,
+		}
+	}
+}
+
+#[derive(Debug, Deserialize)]
+#[napi(string_enum)]
+pub enum Affiliation {
+	/// 用户拥有的仓库
+	Owner,
+	/// 用户作为协作者的仓库
+	Collaborator,
+	/// 用户所在组织的仓库
+	OrganizationMember,
+}
+
+impl From<Affiliation> for nipaw_core::option::Affiliation {
+	fn from(value: Affiliation) -> Self {
+		match value {
+			Affiliation::Owner => nipaw_core::option::Affiliation::Owner,
+			Affiliation::Collaborator => nipaw_core::option::Affiliation::Collaborator,
+			Affiliation::OrganizationMember => nipaw_core::option::Affiliation::OrganizationMember,
+		}
+	}
+}
+
+#[derive(Debug, Deserialize)]
+#[napi(string_enum)]
+pub enum RepoType {
+	/// 全部仓库
+	All,
+	/// 用户拥有的仓库
+	Owner,
+	/// 公开仓库
+	Public,
+	/// 私有仓库
+	Private,
+	/// 用户作为成员参与的仓库
+	Member,
+}
+
+impl From<RepoType> for nipaw_core::option::RepoType {
+	fn from(value: RepoType) -> Self {
+		match value {
+			RepoType::All => nipaw_core::option::RepoType::All,
+			RepoType::Owner => nipaw_core::option::RepoType::Owner,
+			RepoType::Public => nipaw_core::option::RepoType::Public,
+			RepoType::Private => nipaw_core::option::RepoType::Private,
+			RepoType::Member => nipaw_core::option::RepoType::Member,
+		}
 	}
 }
 
@@ -32,6 +128,8 @@ pub struct CommitListOptions {
 	pub since: Option<DateTime<Utc>>,
 	/// 筛选到此时间结束
 	pub until: Option<DateTime<Utc>>,
+	/// 本次调用每个请求的超时毫秒数
+	pub timeout: Option<u32>,
 }
 impl From<CommitListOptions> for nipaw_core::option::CommitListOptions {
 	fn from(value: CommitListOptions) -> Self {
@@ -42,6 +140,290 @@ impl From<CommitListOptions> for nipaw_core::option::CommitListOptions {
 			author: value.author,
 			since: value.since,
 			until: value.until,
+			timeout: value.timeout.map(|timeout| Duration::from_millis(timeout.into())),
+		}
+	}
+}
+
+#[derive(Debug, Deserialize)]
+#[napi(object)]
+pub struct BranchListOptions {
+	/// 每页数量，默认 30，最大 100
+	pub per_page: Option<u32>,
+	/// 页码，默认 1
+	pub page: Option<u32>,
+	/// 本次调用每个请求的超时毫秒数
+	pub timeout: Option<u32>,
+}
+
+impl From<BranchListOptions> for nipaw_core::option::BranchListOptions {
+	fn from(value: BranchListOptions) -> Self {
+		nipaw_core::option::BranchListOptions {
+			per_page: value.per_page,
+			page: value.page,
+			timeout: value.timeout.map(|timeout| Duration::from_millis(timeout.into())),
+		}
+	}
+}
+
+#[derive(Debug, Deserialize)]
+#[napi(object)]
+pub struct TagListOptions {
+	/// 每页数量，默认 30，最大 100
+	pub per_page: Option<u32>,
+	/// 页码，默认 1
+	pub page: Option<u32>,
+	/// 本次调用每个请求的超时毫秒数
+	pub timeout: Option<u32>,
+}
+
+impl From<TagListOptions> for nipaw_core::option::TagListOptions {
+	fn from(value: TagListOptions) -> Self {
+		nipaw_core::option::TagListOptions {
+			per_page: value.per_page,
+			page: value.page,
+			timeout: value.timeout.map(|timeout| Duration::from_millis(timeout.into())),
+		}
+	}
+}
+
+#[derive(Debug, Deserialize)]
+#[napi(object)]
+pub struct ReleaseListOptions {
+	/// 每页数量，默认 30，最大 100
+	pub per_page: Option<u32>,
+	/// 页码，默认 1
+	pub page: Option<u32>,
+	/// 本次调用每个请求的超时毫秒数
+	pub timeout: Option<u32>,
+}
+
+impl From<ReleaseListOptions> for nipaw_core::option::ReleaseListOptions {
+	fn from(value: ReleaseListOptions) -> Self {
+		nipaw_core::option::ReleaseListOptions {
+			per_page: value.per_page,
+			page: value.page,
+			timeout: value.timeout.map(|timeout| Duration::from_millis(timeout.into())),
+		}
+	}
+}
+
+#[derive(Debug, Deserialize)]
+#[napi(object)]
+pub struct ReleaseOptions {
+	/// 标签名称
+	pub tag_name: String,
+	/// 标签不存在时用于创建标签的分支名或提交SHA，默认为仓库默认分支
+	pub target_commitish: Option<String>,
+	/// 发布标题，默认为标签名称
+	pub name: Option<String>,
+	/// 发布说明
+	pub body: Option<String>,
+	/// 是否为草稿，Gitee 和 GitCode 不支持
+	pub draft: Option<bool>,
+	/// 是否为预发布
+	pub prerelease: Option<bool>,
+}
+
+impl From<ReleaseOptions> for nipaw_core::option::ReleaseOptions {
+	fn from(value: ReleaseOptions) -> Self {
+		nipaw_core::option::ReleaseOptions {
+			tag_name: value.tag_name,
+			target_commitish: value.target_commitish,
+			name: value.name,
+			body: value.body,
+			draft: value.draft,
+			prerelease: value.prerelease,
+		}
+	}
+}
+
+#[derive(Debug, Deserialize)]
+#[napi(object)]
+pub struct ReleaseUpdateOptions {
+	/// 标签名称
+	pub tag_name: Option<String>,
+	/// 标签不存在时用于创建标签的分支名或提交SHA
+	pub target_commitish: Option<String>,
+	/// 发布标题
+	pub name: Option<String>,
+	/// 发布说明
+	pub body: Option<String>,
+	/// 是否为草稿，Gitee 和 GitCode 不支持
+	pub draft: Option<bool>,
+	/// 是否为预发布
+	pub prerelease: Option<bool>,
+}
+
+impl From<ReleaseUpdateOptions> for nipaw_core::option::ReleaseUpdateOptions {
+	fn from(value: ReleaseUpdateOptions) -> Self {
+		nipaw_core::option::ReleaseUpdateOptions {
+			tag_name: value.tag_name,
+			target_commitish: value.target_commitish,
+			name: value.name,
+			body: value.body,
+			draft: value.draft,
+			prerelease: value.prerelease,
+		}
+	}
+}
+
+#[derive(Debug, Deserialize)]
+#[napi(object)]
+pub struct IssueListOptions {
+	/// 每页数量，默认 30，最大 100
+	pub per_page: Option<u32>,
+	/// 页码，默认 1
+	pub page: Option<u32>,
+	/// 筛选状态，默认只获取开启的 Issue
+	pub state: Option<IssueStateFilter>,
+	/// 筛选标签，需要同时包含全部标签
+	pub labels: Option<Vec<String>>,
+	/// 筛选指派的用户名
+	pub assignee: Option<String>,
+	/// 筛选创建者用户名
+	pub creator: Option<String>,
+	/// 筛选此时间之后更新的 Issue
+	pub since: Option<DateTime<Utc>>,
+	/// 排序字段
+	pub sort: Option<IssueSort>,
+	/// 排序方向
+	pub direction: Option<Direction>,
+	/// 本次调用每个请求的超时毫秒数
+	pub timeout: Option<u32>,
+}
+
+impl From<IssueListOptions> for nipaw_core::option::IssueListOptions {
+	fn from(value: IssueListOptions) -> Self {
+		nipaw_core::option::IssueListOptions {
+			per_page: value.per_page,
+			page: value.page,
+			state: value.state.map(Into::into),
+			labels: value.labels,
+			assignee: value.assignee,
+			creator: value.creator,
+			since: value.since,
+			sort: value.sort.map(Into::into),
+			direction: value.direction.map(Into::into),
+			timeout: value.timeout.map(|timeout| Duration::from_millis(timeout.into())),
+		}
+	}
+}
+
+#[derive(Debug, Deserialize)]
+#[napi(string_enum)]
+pub enum IssueStateFilter {
+	/// 开启的 Issue
+	Open,
+	/// 已关闭的 Issue
+	Closed,
+	/// 全部 Issue
+	All,
+}
+
+impl From<IssueStateFilter> for nipaw_core::option::IssueStateFilter {
+	fn from(value: IssueStateFilter) -> Self {
+		match value {
+			IssueStateFilter::Open => nipaw_core::option::IssueStateFilter::Open,
+			IssueStateFilter::Closed => nipaw_core::option::IssueStateFilter::Closed,
+			IssueStateFilter::All => nipaw_core::option::IssueStateFilter::All,
+		}
+	}
+}
+
+/// Gitee、GitCode 和 CNB 不支持 `Comments`
+#[derive(Debug, Deserialize)]
+#[napi(string_enum)]
+pub enum IssueSort {
+	/// 创建时间
+	Created,
+	/// 更新时间
+	Updated,
+	/// 评论数量
+	Comments,
+}
+
+impl From<IssueSort> for nipaw_core::option::IssueSort {
+	fn from(value: IssueSort) -> Self {
+		match value {
+			IssueSort::Created => nipaw_core::option::IssueSort::Created,
+			IssueSort::Updated => nipaw_core::option::IssueSort::Updated,
+			IssueSort::Comments => nipaw_core::option::IssueSort::Comments,
+		}
+	}
+}
+
+#[derive(Debug, Deserialize)]
+#[napi(object)]
+pub struct IssueOptions {
+	/// 标题
+	pub title: String,
+	/// 内容
+	pub body: Option<String>,
+	/// 标签名称列表
+	pub labels: Option<Vec<String>>,
+	/// 指派的用户名列表，Gitee 和 GitCode 只支持指派一个用户
+	pub assignees: Option<Vec<String>>,
+	/// 里程碑编号，CNB 不支持
+	pub milestone: Option<u32>,
+}
+
+impl From<IssueOptions> for nipaw_core::option::IssueOptions {
+	fn from(value: IssueOptions) -> Self {
+		nipaw_core::option::IssueOptions {
+			title: value.title,
+			body: value.body,
+			labels: value.labels,
+			assignees: value.assignees,
+			milestone: value.milestone.map(Into::into),
+		}
+	}
+}
+
+#[derive(Debug, Deserialize)]
+#[napi(object)]
+pub struct IssueUpdateOptions {
+	/// 标题
+	pub title: Option<String>,
+	/// 内容
+	pub body: Option<String>,
+	/// 标签名称列表，会覆盖原有标签
+	pub labels: Option<Vec<String>>,
+	/// 指派的用户名列表，会覆盖原有指派，Gitee 和 GitCode 只支持指派一个用户
+	pub assignees: Option<Vec<String>>,
+	/// 里程碑编号，CNB 不支持
+	pub milestone: Option<u32>,
+}
+
+impl From<IssueUpdateOptions> for nipaw_core::option::IssueUpdateOptions {
+	fn from(value: IssueUpdateOptions) -> Self {
+		nipaw_core::option::IssueUpdateOptions {
+			title: value.title,
+			body: value.body,
+			labels: value.labels,
+			assignees: value.assignees,
+			milestone: value.milestone.map(Into::into),
+		}
+	}
+}
+
+#[derive(Debug, Deserialize)]
+#[napi(object)]
+pub struct CommentListOptions {
+	/// 每页数量，默认 30，最大 100
+	pub per_page: Option<u32>,
+	/// 页码，默认 1
+	pub page: Option<u32>,
+	/// 本次调用每个请求的超时毫秒数
+	pub timeout: Option<u32>,
+}
+
+impl From<CommentListOptions> for nipaw_core::option::CommentListOptions {
+	fn from(value: CommentListOptions) -> Self {
+		nipaw_core::option::CommentListOptions {
+			per_page: value.per_page,
+			page: value.page,
+			timeout: value.timeout.map(|timeout| Duration::from_millis(timeout.into())),
 		}
 	}
 }
@@ -53,10 +435,99 @@ pub struct OrgRepoListOptions {
 	pub per_page: Option<u32>,
 	/// 页码，默认 1
 	pub page: Option<u32>,
+	/// 筛选仓库类型，默认全部
+	#[napi(js_name = "type")]
+	#[serde(rename = "type")]
+	pub repo_type: Option<OrgRepoType>,
+	/// 排序字段
+	pub sort: Option<RepoSort>,
+	/// 排序方向
+	pub direction: Option<Direction>,
+	/// 本次调用每个请求的超时毫秒数
+	pub timeout: Option<u32>,
 }
 
 impl From<OrgRepoListOptions> for nipaw_core::option::OrgRepoListOptions {
 	fn from(value: OrgRepoListOptions) -> Self {
-		nipaw_core::option::OrgRepoListOptions { per_page: value.per_page, page: value.page }
+		nipaw_core::option::OrgRepoListOptions {
+			per_page: value.per_page,
+			page: value.page,
+			repo_type: value.repo_type.map(Into::into),
+			sort: value.sort.map(Into::into),
+			direction: value.direction.map(Into::into),
+			timeout: value.timeout.map(|timeout| Duration::from_millis(timeout.into())),
+		}
+	}
+}
+
+#[derive(Debug, Deserialize)]
+#[napi(string_enum)]
+pub enum OrgRepoType {
+	/// 全部仓库
+	All,
+	/// 公开仓库
+	Public,
+	/// 私有仓库
+	Private,
+	/// fork 的仓库
+	Forks,
+	/// 非 fork 的仓库
+	Sources,
+	/// 作为成员参与的仓库
+	Member,
+}
+
+impl From<OrgRepoType> for nipaw_core::option::OrgRepoType {
+	fn from(value: OrgRepoType) -> Self {
+		match value {
+			OrgRepoType::All => nipaw_core::option::OrgRepoType::All,
+			OrgRepoType::Public => nipaw_core::option::OrgRepoType::Public,
+			OrgRepoType::Private => nipaw_core::option::OrgRepoType::Private,
+			OrgRepoType::Forks => nipaw_core::option::OrgRepoType::Forks,
+			OrgRepoType::Sources => nipaw_core::option::OrgRepoType::Sources,
+			OrgRepoType::Member => nipaw_core::option::OrgRepoType::Member,
+		}
+	}
+}
+
+#[derive(Debug, Deserialize)]
+#[napi(string_enum)]
+pub enum RepoSort {
+	/// 创建时间
+	Created,
+	/// 更新时间
+	Updated,
+	/// 推送时间
+	Pushed,
+	/// 仓库全名
+	FullName,
+}
+
+impl From<RepoSort> for nipaw_core::option::RepoSort {
+	fn from(value: RepoSort) -> Self {
+		match value {
+			RepoSort::Created => nipaw_core::option::RepoSort::Created,
+			RepoSort::Updated => nipaw_core::option::RepoSort::Updated,
+			RepoSort::Pushed => nipaw_core::option::RepoSort::Pushed,
+			RepoSort::FullName => nipaw_core::option::RepoSort::FullName,
+		}
+	}
+}
+
+#[derive(Debug, Deserialize)]
+#[napi(string_enum)]
+pub enum Direction {
+	/// 升序
+	Asc,
+	/// 降序
+	Desc,
+}
+
+impl From<Direction> for nipaw_core::option::Direction {
+	fn from(value: Direction) -> Self {
+		match value {
+			Direction::Asc => nipaw_core::option::Direction::Asc,
+			Direction::Desc => nipaw_core::option::Direction::Desc,
+		}
 	}
 }
@@ -1,20 +1,32 @@
 use crate::{
 	common::RT_RUNTIME,
 	error,
-	option::{CommitListOptions, OrgRepoListOptions, ReposListOptions},
+	option::{
+		BranchListOptions, CommentListOptions, CommitListOptions, IssueListOptions, IssueOptions,
+		IssueUpdateOptions, OrgRepoListOptions, ReleaseListOptions, ReleaseOptions,
+		ReleaseUpdateOptions, ReposListOptions, TagListOptions,
+	},
 	types::{
+		branch::BranchInfo,
 		collaborator::{CollaboratorPermission, CollaboratorResult},
+		comment::{CommentInfo, CommentTarget},
 		commit::CommitInfo,
+		issue::IssueInfo,
 		org::OrgInfo,
+		rate_limit::RateLimitInfo,
+		release::{ReleaseAsset, ReleaseInfo},
 		repo::RepoInfo,
+		tag::TagInfo,
 		user::{ContributionResult, UserInfo},
 	},
 };
+use futures::StreamExt;
 use napi::tokio::sync::{RwLock, RwLockWriteGuard};
 use napi_derive::napi;
-use nipaw_core::Client;
+use nipaw_core::{Client, types::release::AssetUpload};
 use paste::paste;
 use std::sync::LazyLock;
+use tokio::io::AsyncWriteExt;
 
 type Result<T> = std::result::Result<T, error::Error>;
 
@@ -258,6 +270,641 @@ macro_rules! impl_client {
 						.await?;
 					Ok(collaborator_result.into())
 				}
+
+				/// 获取仓库分支列表
+				///
+				/// ## 参数
+				/// - `owner` 仓库所有者
+				/// - `repo` 仓库名称
+				/// - `option` 分支列表选项
+				#[napi]
+				pub async fn get_branches(
+					&self,
+					owner: String,
+					repo: String,
+					option: Option<BranchListOptions>,
+				) -> Result<Vec<BranchInfo>> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let branches = client
+						.get_branches((owner.as_str(), repo.as_str()), option.map(|o| o.into()))
+						.await?;
+					Ok(branches.into_iter().map(|v| v.into()).collect())
+				}
+
+				/// 获取仓库分支信息
+				///
+				/// ## 参数
+				/// - `owner` 仓库所有者
+				/// - `repo` 仓库名称
+				/// - `branch` 分支名称
+				#[napi]
+				pub async fn get_branch(
+					&self,
+					owner: String,
+					repo: String,
+					branch: String,
+				) -> Result<BranchInfo> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let branch =
+						client.get_branch((owner.as_str(), repo.as_str()), branch.as_str()).await?;
+					Ok(branch.into())
+				}
+
+				/// 创建仓库分支
+				///
+				/// ## 参数
+				/// - `owner` 仓库所有者
+				/// - `repo` 仓库名称
+				/// - `branch` 新分支名称
+				/// - `from` 起点, 可以是分支名、标签名或提交SHA
+				#[napi]
+				pub async fn create_branch(
+					&self,
+					owner: String,
+					repo: String,
+					branch: String,
+					from: String,
+				) -> Result<BranchInfo> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let branch = client
+						.create_branch((owner.as_str(), repo.as_str()), branch.as_str(), from.as_str())
+						.await?;
+					Ok(branch.into())
+				}
+
+				/// 删除仓库分支, Gitee 不支持
+				///
+				/// ## 参数
+				/// - `owner` 仓库所有者
+				/// - `repo` 仓库名称
+				/// - `branch` 分支名称
+				#[napi]
+				pub async fn delete_branch(
+					&self,
+					owner: String,
+					repo: String,
+					branch: String,
+				) -> Result<()> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					client.delete_branch((owner.as_str(), repo.as_str()), branch.as_str()).await?;
+					Ok(())
+				}
+
+				/// 获取仓库标签列表
+				///
+				/// ## 参数
+				/// - `owner` 仓库所有者
+				/// - `repo` 仓库名称
+				/// - `option` 标签列表选项
+				#[napi]
+				pub async fn get_tags(
+					&self,
+					owner: String,
+					repo: String,
+					option: Option<TagListOptions>,
+				) -> Result<Vec<TagInfo>> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let tags = client
+						.get_tags((owner.as_str(), repo.as_str()), option.map(|o| o.into()))
+						.await?;
+					Ok(tags.into_iter().map(|v| v.into()).collect())
+				}
+
+				/// 获取仓库标签信息
+				///
+				/// ## 参数
+				/// - `owner` 仓库所有者
+				/// - `repo` 仓库名称
+				/// - `tag` 标签名称
+				#[napi]
+				pub async fn get_tag(&self, owner: String, repo: String, tag: String) -> Result<TagInfo> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let tag = client.get_tag((owner.as_str(), repo.as_str()), tag.as_str()).await?;
+					Ok(tag.into())
+				}
+
+				/// 创建仓库标签
+				///
+				/// ## 参数
+				/// - `owner` 仓库所有者
+				/// - `repo` 仓库名称
+				/// - `tag` 标签名称
+				/// - `target` 标签指向的分支名或提交SHA
+				/// - `message` 标签说明, 设置时创建附注标签
+				#[napi]
+				pub async fn create_tag(
+					&self,
+					owner: String,
+					repo: String,
+					tag: String,
+					target: String,
+					message: Option<String>,
+				) -> Result<TagInfo> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let tag = client
+						.create_tag(
+							(owner.as_str(), repo.as_str()),
+							tag.as_str(),
+							target.as_str(),
+							message.as_deref(),
+						)
+						.await?;
+					Ok(tag.into())
+				}
+
+				/// 删除仓库标签, Gitee 不支持
+				///
+				/// ## 参数
+				/// - `owner` 仓库所有者
+				/// - `repo` 仓库名称
+				/// - `tag` 标签名称
+				#[napi]
+				pub async fn delete_tag(&self, owner: String, repo: String, tag: String) -> Result<()> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					client.delete_tag((owner.as_str(), repo.as_str()), tag.as_str()).await?;
+					Ok(())
+				}
+
+				/// 获取仓库发布列表
+				///
+				/// ## 参数
+				/// - `owner` 仓库所有者
+				/// - `repo` 仓库名称
+				/// - `option` 发布列表选项
+				#[napi]
+				pub async fn get_releases(
+					&self,
+					owner: String,
+					repo: String,
+					option: Option<ReleaseListOptions>,
+				) -> Result<Vec<ReleaseInfo>> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let releases = client
+						.get_releases((owner.as_str(), repo.as_str()), option.map(|o| o.into()))
+						.await?;
+					Ok(releases.into_iter().map(|v| v.into()).collect())
+				}
+
+				/// 获取最新发布
+				///
+				/// ## 参数
+				/// - `owner` 仓库所有者
+				/// - `repo` 仓库名称
+				#[napi]
+				pub async fn get_latest_release(&self, owner: String, repo: String) -> Result<ReleaseInfo> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let release = client.get_latest_release((owner.as_str(), repo.as_str())).await?;
+					Ok(release.into())
+				}
+
+				/// 获取指定标签的发布
+				///
+				/// ## 参数
+				/// - `owner` 仓库所有者
+				/// - `repo` 仓库名称
+				/// - `tag` 标签名称
+				#[napi]
+				pub async fn get_release_by_tag(
+					&self,
+					owner: String,
+					repo: String,
+					tag: String,
+				) -> Result<ReleaseInfo> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let release =
+						client.get_release_by_tag((owner.as_str(), repo.as_str()), tag.as_str()).await?;
+					Ok(release.into())
+				}
+
+				/// 创建发布
+				///
+				/// ## 参数
+				/// - `owner` 仓库所有者
+				/// - `repo` 仓库名称
+				/// - `option` 发布选项
+				#[napi]
+				pub async fn create_release(
+					&self,
+					owner: String,
+					repo: String,
+					option: ReleaseOptions,
+				) -> Result<ReleaseInfo> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let release =
+						client.create_release((owner.as_str(), repo.as_str()), option.into()).await?;
+					Ok(release.into())
+				}
+
+				/// 更新发布
+				///
+				/// ## 参数
+				/// - `owner` 仓库所有者
+				/// - `repo` 仓库名称
+				/// - `release_id` 发布ID
+				/// - `option` 发布选项，未设置的字段保持不变
+				#[napi]
+				pub async fn update_release(
+					&self,
+					owner: String,
+					repo: String,
+					release_id: String,
+					option: ReleaseUpdateOptions,
+				) -> Result<ReleaseInfo> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let release = client
+						.update_release((owner.as_str(), repo.as_str()), release_id.as_str(), option.into())
+						.await?;
+					Ok(release.into())
+				}
+
+				/// 删除发布
+				///
+				/// ## 参数
+				/// - `owner` 仓库所有者
+				/// - `repo` 仓库名称
+				/// - `release_id` 发布ID
+				#[napi]
+				pub async fn delete_release(
+					&self,
+					owner: String,
+					repo: String,
+					release_id: String,
+				) -> Result<()> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					client.delete_release((owner.as_str(), repo.as_str()), release_id.as_str()).await?;
+					Ok(())
+				}
+
+				/// 上传本地文件作为发布附件
+				///
+				/// ## 参数
+				/// - `owner` 仓库所有者
+				/// - `repo` 仓库名称
+				/// - `release_id` 发布ID
+				/// - `path` 本地文件路径
+				/// - `name` 附件名称, 默认为文件名
+				/// - `content_type` 附件的 MIME 类型, 默认为 `application/octet-stream`
+				#[napi]
+				pub async fn upload_release_asset(
+					&self,
+					owner: String,
+					repo: String,
+					release_id: String,
+					path: String,
+					name: Option<String>,
+					content_type: Option<String>,
+				) -> Result<ReleaseAsset> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let mut asset = AssetUpload::from_file(&path).await?;
+					if let Some(name) = name {
+						asset.name = name;
+					}
+					asset.content_type = content_type;
+					let asset = client
+						.upload_release_asset((owner.as_str(), repo.as_str()), release_id.as_str(), asset)
+						.await?;
+					Ok(asset.into())
+				}
+
+				/// 下载发布附件到本地文件
+				///
+				/// ## 参数
+				/// - `owner` 仓库所有者
+				/// - `repo` 仓库名称
+				/// - `release_id` 发布ID
+				/// - `asset_id` 附件ID
+				/// - `path` 保存的本地文件路径
+				#[napi]
+				pub async fn download_release_asset(
+					&self,
+					owner: String,
+					repo: String,
+					release_id: String,
+					asset_id: String,
+					path: String,
+				) -> Result<()> {
+					// 获取字节流后即释放客户端锁, 写入文件期间不阻塞其他调用
+					let mut stream = {
+						let client = [<create_client_ $client_type:lower>]().await;
+						client
+							.download_release_asset(
+								(owner.as_str(), repo.as_str()),
+								release_id.as_str(),
+								asset_id.as_str(),
+							)
+							.await?
+					};
+					let mut file =
+						tokio::fs::File::create(&path).await.map_err(nipaw_core::Error::from)?;
+					while let Some(chunk) = stream.next().await {
+						file.write_all(&chunk?).await.map_err(nipaw_core::Error::from)?;
+					}
+					file.flush().await.map_err(nipaw_core::Error::from)?;
+					Ok(())
+				}
+
+				/// 删除发布附件
+				///
+				/// ## 参数
+				/// - `owner` 仓库所有者
+				/// - `repo` 仓库名称
+				/// - `release_id` 发布ID
+				/// - `asset_id` 附件ID
+				#[napi]
+				pub async fn delete_release_asset(
+					&self,
+					owner: String,
+					repo: String,
+					release_id: String,
+					asset_id: String,
+				) -> Result<()> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					client
+						.delete_release_asset(
+							(owner.as_str(), repo.as_str()),
+							release_id.as_str(),
+							asset_id.as_str(),
+						)
+						.await?;
+					Ok(())
+				}
+
+				/// 获取仓库 Issue 列表，不包含 PR
+				///
+				/// ## 参数
+				/// - `owner` 仓库所有者
+				/// - `repo` 仓库名称
+				/// - `option` Issue 列表选项
+				#[napi]
+				pub async fn get_issues(
+					&self,
+					owner: String,
+					repo: String,
+					option: Option<IssueListOptions>,
+				) -> Result<Vec<IssueInfo>> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let issues = client
+						.get_issues((owner.as_str(), repo.as_str()), option.map(|o| o.into()))
+						.await?;
+					Ok(issues.into_iter().map(|v| v.into()).collect())
+				}
+
+				/// 获取单个 Issue
+				///
+				/// ## 参数
+				/// - `owner` 仓库所有者
+				/// - `repo` 仓库名称
+				/// - `number` Issue 编号
+				#[napi]
+				pub async fn get_issue(
+					&self,
+					owner: String,
+					repo: String,
+					number: String,
+				) -> Result<IssueInfo> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let issue = client.get_issue((owner.as_str(), repo.as_str()), number.as_str()).await?;
+					Ok(issue.into())
+				}
+
+				/// 创建 Issue
+				///
+				/// ## 参数
+				/// - `owner` 仓库所有者
+				/// - `repo` 仓库名称
+				/// - `option` Issue 选项
+				#[napi]
+				pub async fn create_issue(
+					&self,
+					owner: String,
+					repo: String,
+					option: IssueOptions,
+				) -> Result<IssueInfo> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let issue =
+						client.create_issue((owner.as_str(), repo.as_str()), option.into()).await?;
+					Ok(issue.into())
+				}
+
+				/// 更新 Issue
+				///
+				/// ## 参数
+				/// - `owner` 仓库所有者
+				/// - `repo` 仓库名称
+				/// - `number` Issue 编号
+				/// - `option` Issue 选项，未设置的字段保持不变
+				#[napi]
+				pub async fn update_issue(
+					&self,
+					owner: String,
+					repo: String,
+					number: String,
+					option: IssueUpdateOptions,
+				) -> Result<IssueInfo> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let issue = client
+						.update_issue((owner.as_str(), repo.as_str()), number.as_str(), option.into())
+						.await?;
+					Ok(issue.into())
+				}
+
+				/// 关闭 Issue
+				///
+				/// ## 参数
+				/// - `owner` 仓库所有者
+				/// - `repo` 仓库名称
+				/// - `number` Issue 编号
+				#[napi]
+				pub async fn close_issue(
+					&self,
+					owner: String,
+					repo: String,
+					number: String,
+				) -> Result<IssueInfo> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let issue =
+						client.close_issue((owner.as_str(), repo.as_str()), number.as_str()).await?;
+					Ok(issue.into())
+				}
+
+				/// 锁定 Issue, 仅 GitHub 支持
+				///
+				/// ## 参数
+				/// - `owner` 仓库所有者
+				/// - `repo` 仓库名称
+				/// - `number` Issue 编号
+				#[napi]
+				pub async fn lock_issue(&self, owner: String, repo: String, number: String) -> Result<()> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					client.lock_issue((owner.as_str(), repo.as_str()), number.as_str()).await?;
+					Ok(())
+				}
+
+				/// 解锁 Issue, 仅 GitHub 支持
+				///
+				/// ## 参数
+				/// - `owner` 仓库所有者
+				/// - `repo` 仓库名称
+				/// - `number` Issue 编号
+				#[napi]
+				pub async fn unlock_issue(&self, owner: String, repo: String, number: String) -> Result<()> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					client.unlock_issue((owner.as_str(), repo.as_str()), number.as_str()).await?;
+					Ok(())
+				}
+
+				/// 获取 Issue 或 PR 的评论列表, PR 的代码评论使用 `getReviewComments` 获取
+				///
+				/// ## 参数
+				/// - `owner` 仓库所有者
+				/// - `repo` 仓库名称
+				/// - `target` 评论所属的对象
+				/// - `number` Issue 或 PR 编号
+				/// - `option` 评论列表选项
+				#[napi]
+				pub async fn get_comments(
+					&self,
+					owner: String,
+					repo: String,
+					target: CommentTarget,
+					number: String,
+					option: Option<CommentListOptions>,
+				) -> Result<Vec<CommentInfo>> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let comments = client
+						.get_comments(
+							(owner.as_str(), repo.as_str()),
+							target.into(),
+							number.as_str(),
+							option.map(|o| o.into()),
+						)
+						.await?;
+					Ok(comments.into_iter().map(|v| v.into()).collect())
+				}
+
+				/// 获取 PR 的代码评论列表, CNB 不支持
+				///
+				/// ## 参数
+				/// - `owner` 仓库所有者
+				/// - `repo` 仓库名称
+				/// - `number` PR 编号
+				/// - `option` 评论列表选项
+				#[napi]
+				pub async fn get_review_comments(
+					&self,
+					owner: String,
+					repo: String,
+					number: String,
+					option: Option<CommentListOptions>,
+				) -> Result<Vec<CommentInfo>> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let comments = client
+						.get_review_comments(
+							(owner.as_str(), repo.as_str()),
+							number.as_str(),
+							option.map(|o| o.into()),
+						)
+						.await?;
+					Ok(comments.into_iter().map(|v| v.into()).collect())
+				}
+
+				/// 发表评论
+				///
+				/// ## 参数
+				/// - `owner` 仓库所有者
+				/// - `repo` 仓库名称
+				/// - `target` 评论所属的对象
+				/// - `number` Issue 或 PR 编号
+				/// - `body` 评论内容
+				#[napi]
+				pub async fn create_comment(
+					&self,
+					owner: String,
+					repo: String,
+					target: CommentTarget,
+					number: String,
+					body: String,
+				) -> Result<CommentInfo> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let comment = client
+						.create_comment(
+							(owner.as_str(), repo.as_str()),
+							target.into(),
+							number.as_str(),
+							body.as_str(),
+						)
+						.await?;
+					Ok(comment.into())
+				}
+
+				/// 编辑评论
+				///
+				/// ## 参数
+				/// - `owner` 仓库所有者
+				/// - `repo` 仓库名称
+				/// - `target` 评论所属的对象
+				/// - `number` Issue 或 PR 编号, 仅 CNB 使用
+				/// - `comment_id` 评论ID
+				/// - `body` 新的评论内容
+				#[napi]
+				pub async fn update_comment(
+					&self,
+					owner: String,
+					repo: String,
+					target: CommentTarget,
+					number: String,
+					comment_id: String,
+					body: String,
+				) -> Result<CommentInfo> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let comment = client
+						.update_comment(
+							(owner.as_str(), repo.as_str()),
+							target.into(),
+							number.as_str(),
+							comment_id.as_str(),
+							body.as_str(),
+						)
+						.await?;
+					Ok(comment.into())
+				}
+
+				/// 删除评论, CNB 不支持
+				///
+				/// ## 参数
+				/// - `owner` 仓库所有者
+				/// - `repo` 仓库名称
+				/// - `target` 评论所属的对象
+				/// - `number` Issue 或 PR 编号, 仅 CNB 使用
+				/// - `comment_id` 评论ID
+				#[napi]
+				pub async fn delete_comment(
+					&self,
+					owner: String,
+					repo: String,
+					target: CommentTarget,
+					number: String,
+					comment_id: String,
+				) -> Result<()> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					client
+						.delete_comment(
+							(owner.as_str(), repo.as_str()),
+							target.into(),
+							number.as_str(),
+							comment_id.as_str(),
+						)
+						.await?;
+					Ok(())
+				}
+
+				/// 获取当前的限流状态
+				#[napi]
+				pub async fn get_rate_limit(&self) -> Result<RateLimitInfo> {
+					let client = [<create_client_ $client_type:lower>]().await;
+					let rate_limit = client.get_rate_limit().await?;
+					Ok(rate_limit.into())
+				}
 			}
 		}
 	};
@@ -1,5 +1,11 @@
+pub(crate) mod branch;
 pub(crate) mod collaborator;
+pub(crate) mod comment;
 pub(crate) mod commit;
+pub(crate) mod issue;
 pub(crate) mod org;
+pub(crate) mod rate_limit;
+pub(crate) mod release;
 pub(crate) mod repo;
+pub(crate) mod tag;
 pub(crate) mod user;
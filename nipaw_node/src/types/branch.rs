@@ -0,0 +1,19 @@
+use napi_derive::napi;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi(object)]
+pub struct BranchInfo {
+	/// 分支名称
+	pub name: String,
+	/// 分支最新提交的SHA
+	pub sha: String,
+	/// 是否为保护分支
+	pub protected: bool,
+}
+
+impl From<nipaw_core::types::branch::BranchInfo> for BranchInfo {
+	fn from(branch: nipaw_core::types::branch::BranchInfo) -> Self {
+		BranchInfo { name: branch.name, sha: branch.sha, protected: branch.protected }
+	}
+}
@@ -0,0 +1,54 @@
+use chrono::{DateTime, Utc};
+use napi_derive::napi;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi(object)]
+pub struct CommentInfo {
+	/// 评论ID
+	pub id: String,
+	/// 评论内容
+	pub body: String,
+	/// 评论者用户名
+	pub author: Option<String>,
+	/// 评论所在的文件路径, 仅 PR 代码评论存在
+	pub path: Option<String>,
+	/// 评论所在的行号, 仅 PR 代码评论存在
+	pub line: Option<u32>,
+	/// 创建时间
+	pub created_at: DateTime<Utc>,
+	/// 更新时间
+	pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<nipaw_core::types::comment::CommentInfo> for CommentInfo {
+	fn from(comment: nipaw_core::types::comment::CommentInfo) -> Self {
+		CommentInfo {
+			id: comment.id,
+			body: comment.body,
+			author: comment.author,
+			path: comment.path,
+			line: comment.line.map(|line| line as u32),
+			created_at: comment.created_at,
+			updated_at: comment.updated_at,
+		}
+	}
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi(string_enum)]
+pub enum CommentTarget {
+	/// Issue
+	Issue,
+	/// PR
+	PullRequest,
+}
+
+impl From<CommentTarget> for nipaw_core::CommentTarget {
+	fn from(target: CommentTarget) -> Self {
+		match target {
+			CommentTarget::Issue => nipaw_core::CommentTarget::Issue,
+			CommentTarget::PullRequest => nipaw_core::CommentTarget::PullRequest,
+		}
+	}
+}
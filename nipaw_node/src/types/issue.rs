@@ -0,0 +1,71 @@
+use chrono::{DateTime, Utc};
+use napi_derive::napi;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi(object)]
+pub struct IssueInfo {
+	/// Issue 编号, Gitee 和 GitCode 为字符串形式的编号
+	pub number: String,
+	/// 标题
+	pub title: String,
+	/// 内容
+	pub body: Option<String>,
+	/// 状态
+	pub state: IssueState,
+	/// 标签名称列表
+	pub labels: Vec<String>,
+	/// 指派的用户名列表
+	pub assignees: Vec<String>,
+	/// 里程碑标题
+	pub milestone: Option<String>,
+	/// 创建者用户名
+	pub author: Option<String>,
+	/// 是否为 PR
+	pub pull_request: bool,
+	/// 创建时间
+	pub created_at: DateTime<Utc>,
+	/// 更新时间
+	pub updated_at: DateTime<Utc>,
+	/// 关闭时间
+	pub closed_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi(string_enum)]
+pub enum IssueState {
+	/// 开启
+	#[serde(rename = "open")]
+	Open,
+	/// 已关闭
+	#[serde(rename = "closed")]
+	Closed,
+}
+
+impl From<nipaw_core::types::issue::IssueState> for IssueState {
+	fn from(state: nipaw_core::types::issue::IssueState) -> Self {
+		match state {
+			nipaw_core::types::issue::IssueState::Open => IssueState::Open,
+			nipaw_core::types::issue::IssueState::Closed => IssueState::Closed,
+		}
+	}
+}
+
+impl From<nipaw_core::types::issue::IssueInfo> for IssueInfo {
+	fn from(issue: nipaw_core::types::issue::IssueInfo) -> Self {
+		IssueInfo {
+			number: issue.number,
+			title: issue.title,
+			body: issue.body,
+			state: issue.state.into(),
+			labels: issue.labels,
+			assignees: issue.assignees,
+			milestone: issue.milestone,
+			author: issue.author,
+			pull_request: issue.pull_request,
+			created_at: issue.created_at,
+			updated_at: issue.updated_at,
+			closed_at: issue.closed_at,
+		}
+	}
+}
@@ -0,0 +1,27 @@
+use chrono::{DateTime, Utc};
+use napi_derive::napi;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi(object)]
+pub struct RateLimitInfo {
+	/// 当前时间窗口内允许的请求数
+	pub limit: Option<u32>,
+	/// 当前时间窗口内剩余的请求数
+	pub remaining: Option<u32>,
+	/// 时间窗口重置的时间
+	pub reset_at: Option<DateTime<Utc>>,
+	/// 平台建议的重试等待秒数
+	pub retry_after: Option<u32>,
+}
+
+impl From<nipaw_core::types::rate_limit::RateLimitInfo> for RateLimitInfo {
+	fn from(rate_limit: nipaw_core::types::rate_limit::RateLimitInfo) -> Self {
+		RateLimitInfo {
+			limit: rate_limit.limit.map(|limit| limit as u32),
+			remaining: rate_limit.remaining.map(|remaining| remaining as u32),
+			reset_at: rate_limit.reset_at,
+			retry_after: rate_limit.retry_after.map(|retry_after| retry_after.as_secs() as u32),
+		}
+	}
+}
@@ -0,0 +1,78 @@
+use chrono::{DateTime, Utc};
+use napi_derive::napi;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi(object)]
+pub struct ReleaseInfo {
+	/// 发布ID, GitCode 的发布ID为标签名称
+	pub id: String,
+	/// 标签名称
+	pub tag_name: String,
+	/// 发布指向的分支名或提交SHA
+	pub target_commitish: Option<String>,
+	/// 发布标题
+	pub name: Option<String>,
+	/// 发布说明
+	pub body: Option<String>,
+	/// 是否为草稿
+	pub draft: bool,
+	/// 是否为预发布
+	pub prerelease: bool,
+	/// 发布者用户名
+	pub author: Option<String>,
+	/// 创建时间
+	pub created_at: Option<DateTime<Utc>>,
+	/// 发布时间
+	pub published_at: Option<DateTime<Utc>>,
+	/// 附件列表
+	pub assets: Vec<ReleaseAsset>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi(object)]
+pub struct ReleaseAsset {
+	/// 附件ID, GitCode 的附件ID为附件名称
+	pub id: String,
+	/// 附件名称
+	pub name: String,
+	/// 附件大小, 单位为字节
+	pub size: Option<i64>,
+	/// 附件的 MIME 类型
+	pub content_type: Option<String>,
+	/// 附件下载地址
+	pub download_url: String,
+	/// 下载次数
+	pub download_count: Option<u32>,
+}
+
+impl From<nipaw_core::types::release::ReleaseInfo> for ReleaseInfo {
+	fn from(release: nipaw_core::types::release::ReleaseInfo) -> Self {
+		ReleaseInfo {
+			id: release.id,
+			tag_name: release.tag_name,
+			target_commitish: release.target_commitish,
+			name: release.name,
+			body: release.body,
+			draft: release.draft,
+			prerelease: release.prerelease,
+			author: release.author,
+			created_at: release.created_at,
+			published_at: release.published_at,
+			assets: release.assets.into_iter().map(Into::into).collect(),
+		}
+	}
+}
+
+impl From<nipaw_core::types::release::ReleaseAsset> for ReleaseAsset {
+	fn from(asset: nipaw_core::types::release::ReleaseAsset) -> Self {
+		ReleaseAsset {
+			id: asset.id,
+			name: asset.name,
+			size: asset.size.map(|size| size as i64),
+			content_type: asset.content_type,
+			download_url: asset.download_url,
+			download_count: asset.download_count.map(|count| count as u32),
+		}
+	}
+}
@@ -0,0 +1,47 @@
+use chrono::{DateTime, Utc};
+use napi_derive::napi;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi(object)]
+pub struct TagInfo {
+	/// 标签名称
+	pub name: String,
+	/// 标签指向的提交SHA
+	pub sha: String,
+	/// 标签创建者, 仅附注标签存在
+	pub tagger: Option<TaggerInfo>,
+	/// 标签说明, 仅附注标签存在
+	pub message: Option<String>,
+	/// 是否为附注标签
+	pub annotated: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[napi(object)]
+pub struct TaggerInfo {
+	/// 名称
+	pub name: String,
+	/// 邮箱
+	pub email: Option<String>,
+	/// 创建时间
+	pub date: Option<DateTime<Utc>>,
+}
+
+impl From<nipaw_core::types::tag::TagInfo> for TagInfo {
+	fn from(tag: nipaw_core::types::tag::TagInfo) -> Self {
+		TagInfo {
+			name: tag.name,
+			sha: tag.sha,
+			tagger: tag.tagger.map(|tagger| tagger.into()),
+			message: tag.message,
+			annotated: tag.annotated,
+		}
+	}
+}
+
+impl From<nipaw_core::types::tag::TaggerInfo> for TaggerInfo {
+	fn from(tagger: nipaw_core::types::tag::TaggerInfo) -> Self {
+		TaggerInfo { name: tagger.name, email: tagger.email, date: tagger.date }
+	}
+}